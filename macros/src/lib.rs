@@ -2,10 +2,10 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse_macro_input, parse_quote, punctuated::Punctuated, token::Comma, Data, DeriveInput,
-    GenericParam, ItemTrait, MetaNameValue, TypeParamBound,
+    GenericParam, Ident, ItemTrait, MetaNameValue, TypeParamBound,
 };
 
-#[proc_macro_derive(Data, attributes(actuate))]
+#[proc_macro_derive(Data, attributes(actuate, data))]
 pub fn derive_data(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ident = &input.ident;
@@ -41,7 +41,14 @@ pub fn derive_data(input: TokenStream) -> TokenStream {
                     #ident: #bounds
                 }
             }
-            GenericParam::Const(const_param) => const_param.to_token_stream(),
+            GenericParam::Const(const_param) => {
+                let ident = &const_param.ident;
+                let ty = &const_param.ty;
+
+                quote! {
+                    const #ident: #ty
+                }
+            }
         })
         .collect();
 
@@ -51,21 +58,41 @@ pub fn derive_data(input: TokenStream) -> TokenStream {
         .map(|param| match param {
             GenericParam::Lifetime(lifetime_param) => lifetime_param.to_token_stream(),
             GenericParam::Type(type_param) => type_param.ident.to_token_stream(),
-            GenericParam::Const(const_param) => const_param.to_token_stream(),
+            GenericParam::Const(const_param) => const_param.ident.to_token_stream(),
         })
         .collect();
 
+    let where_clause = &generics.where_clause;
+
     let Data::Struct(input_struct) = input.data else {
         todo!()
     };
 
-    let checks = input_struct.fields.iter().map(|field| {
+    let mut errors = Vec::new();
+    let fields = input_struct.fields.iter().filter(|field| {
+        let mut is_skipped = false;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("data"))
+        {
+            match attr.parse_args::<Ident>() {
+                Ok(arg) if arg == "skip" => is_skipped = true,
+                _ => errors.push(
+                    syn::Error::new_spanned(attr, "expected `#[data(skip)]`").to_compile_error(),
+                ),
+            }
+        }
+        !is_skipped
+    });
+
+    let checks = fields.map(|field| {
         let field_ident = field.ident.as_ref().unwrap();
         let check_ident = format_ident!("__check_{}_{}", ident, field_ident);
         quote! {
            #[doc(hidden)]
-           #[allow(non_snake_case)]
-           fn #check_ident <#generic_params> (t: #ident <#generic_ty_params>) {
+           #[allow(non_snake_case, clippy::multiple_bound_locations)]
+           fn #check_ident <#generic_params> (t: #ident <#generic_ty_params>) #where_clause {
                 use #actuate::data::{FieldWrap, DataField, FnField, StaticField};
 
                 (&&FieldWrap(t.#field_ident)).check()
@@ -73,11 +100,16 @@ pub fn derive_data(input: TokenStream) -> TokenStream {
         }
     });
 
+    let checks: Vec<_> = checks.collect();
+
     let gen = quote! {
+        #( #errors )*
+
         #( #checks )*
 
         #[doc(hidden)]
-        unsafe impl <#generic_params> #actuate::data::Data for #ident <#generic_ty_params> {}
+        #[allow(clippy::multiple_bound_locations)]
+        unsafe impl <#generic_params> #actuate::data::Data for #ident <#generic_ty_params> #where_clause {}
     };
     gen.into()
 }
@@ -115,3 +147,120 @@ pub fn data(_attrs: TokenStream, input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+#[proc_macro_derive(Props, attributes(props))]
+pub fn derive_props(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let builder_ident = format_ident!("{}Builder", ident);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Struct(input_struct) = &input.data else {
+        return syn::Error::new_spanned(ident, "`Props` can only be derived for structs")
+            .to_compile_error()
+            .into();
+    };
+
+    let mut errors = Vec::new();
+    let mut fields = Vec::new();
+    for field in &input_struct.fields {
+        let Some(field_ident) = &field.ident else {
+            errors.push(
+                syn::Error::new_spanned(field, "`Props` requires named fields").to_compile_error(),
+            );
+            continue;
+        };
+
+        let mut is_required = false;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("props"))
+        {
+            match attr.parse_args::<Ident>() {
+                Ok(arg) if arg == "required" => is_required = true,
+                _ => errors.push(
+                    syn::Error::new_spanned(attr, "expected `#[props(required)]`")
+                        .to_compile_error(),
+                ),
+            }
+        }
+
+        fields.push((field_ident, &field.ty, is_required));
+    }
+
+    let builder_fields = fields.iter().map(|(field_ident, ty, _)| {
+        quote! {
+            #field_ident: Option<#ty>
+        }
+    });
+
+    let empty_fields = fields.iter().map(|(field_ident, _, _)| {
+        quote! {
+            #field_ident: None
+        }
+    });
+
+    let setters = fields.iter().map(|(field_ident, ty, _)| {
+        let doc = format!("Set the `{}` prop.", field_ident);
+        quote! {
+            #[doc = #doc]
+            pub fn #field_ident(mut self, value: impl Into<#ty>) -> Self {
+                self.#field_ident = Some(value.into());
+                self
+            }
+        }
+    });
+
+    let build_fields = fields.iter().map(|(field_ident, _, is_required)| {
+        if *is_required {
+            let msg = format!("Missing required prop `{}` for `{}`.", field_ident, ident);
+            quote! {
+                #field_ident: self.#field_ident.expect(#msg)
+            }
+        } else {
+            quote! {
+                #field_ident: self.#field_ident.unwrap_or_default()
+            }
+        }
+    });
+
+    let builder_doc = format!("Builder for [`{}`].", ident);
+    let builder_fn_doc = format!("Create a new builder for [`{}`].", ident);
+
+    quote! {
+        #( #errors )*
+
+        #[doc = #builder_doc]
+        #[must_use]
+        #vis struct #builder_ident #impl_generics #where_clause {
+            #( #builder_fields ),*
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            pub fn builder() -> #builder_ident #ty_generics {
+                #builder_ident {
+                    #( #empty_fields ),*
+                }
+            }
+        }
+
+        impl #impl_generics #builder_ident #ty_generics #where_clause {
+            #( #setters )*
+
+            /// Build the props, using the default value for any unset optional props.
+            ///
+            /// # Panics
+            /// Panics if a required prop was not set.
+            pub fn build(self) -> #ident #ty_generics {
+                #ident {
+                    #( #build_fields ),*
+                }
+            }
+        }
+    }
+    .into()
+}
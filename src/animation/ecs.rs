@@ -1,6 +1,6 @@
 use crate::{
     data::Data,
-    ecs::{use_world, use_world_once},
+    ecs::{use_world, use_world_once, use_world_value},
     use_local_task, use_mut, use_ref, ScopeState, Signal, SignalMut,
 };
 use bevy_ecs::prelude::*;
@@ -20,8 +20,43 @@ struct State<T> {
     tx: Option<oneshot::Sender<()>>,
 }
 
+/// Use the time this scope has been mounted for, from the ECS [`Time`] resource.
+///
+/// This scope is re-composed on every frame with the latest elapsed time.
+/// To compute elapsed times without re-composing, see [`use_mount_time`](super::use_mount_time).
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Item {
+///     idx: usize,
+/// }
+///
+/// impl Compose for Item {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let elapsed = use_elapsed(&cx).as_secs_f32();
+///
+///         // Fade each item in after the last.
+///         let delay = cx.me().idx as f32 * 0.1;
+///         let alpha = ((elapsed - delay) / 0.3).clamp(0., 1.);
+///
+///         spawn((Text::new("Item"), TextColor(Color::WHITE.with_alpha(alpha))))
+///     }
+/// }
+/// ```
+pub fn use_elapsed(cx: ScopeState<'_>) -> Duration {
+    let now = use_world_value(cx, |time: Res<Time>| time.elapsed());
+    let mount_time = use_ref(cx, || *now);
+
+    now.saturating_sub(*mount_time)
+}
+
 /// Use an animated value.
-pub fn use_animated<T>(cx: ScopeState, make_initial: impl FnOnce() -> T) -> UseAnimated<T>
+pub fn use_animated<T>(cx: ScopeState<'_>, make_initial: impl FnOnce() -> T) -> UseAnimated<'_, T>
 where
     T: VectorSpace + Send + 'static,
 {
@@ -0,0 +1,137 @@
+use crate::{use_mut, use_ref, ScopeState, Signal, SignalMut};
+use alloc::{rc::Rc, sync::Arc};
+use core::{
+    cell::{Cell, RefCell},
+    ops::{Add, Mul, Sub},
+    time::Duration,
+};
+
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+#[cfg(feature = "animation")]
+mod ecs;
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+pub use self::ecs::{use_animated, use_elapsed, AnimationController, UseAnimated};
+
+/// Use the instant this scope was first composed.
+///
+/// Unlike [`use_elapsed`](crate::animation::use_elapsed), this doesn't re-compose this scope,
+/// so elapsed times can be computed when needed (e.g. with [`Instant::elapsed`]).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn use_mount_time(cx: ScopeState<'_>) -> Instant {
+    *use_ref(cx, Instant::now)
+}
+
+/// Clock to drive animations.
+///
+/// This can be implemented to animate values without the Bevy ECS (see [`use_animated_with_clock`]).
+pub trait Clock {
+    /// Get the current time.
+    fn now(&self) -> Duration;
+}
+
+macro_rules! impl_clock {
+    ($($t:tt),*) => {
+        $(
+            impl<T: Clock + ?Sized> Clock for $t<T> {
+                fn now(&self) -> Duration {
+                    (**self).now()
+                }
+            }
+        )*
+    };
+}
+
+impl_clock!(Box, Rc, Arc);
+
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+struct ClockState<T> {
+    from: T,
+    to: T,
+    start: Duration,
+}
+
+/// Use a value animated towards `target` with the provided [`Clock`].
+///
+/// Whenever `target` changes, the returned value will be interpolated from its current value to `target` over `duration`.
+/// While animating, this scope will be re-composed on each composition until the animation is complete.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::time::{Duration, Instant};
+///
+/// struct SystemClock(Instant);
+///
+/// impl Clock for SystemClock {
+///     fn now(&self) -> Duration {
+///         self.0.elapsed()
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App {
+///     is_open: bool,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let clock = use_ref(&cx, || SystemClock(Instant::now()));
+///
+///         let target: f32 = if cx.me().is_open { 100. } else { 0. };
+///         let height = use_animated_with_clock(&cx, clock, target, Duration::from_millis(300));
+///
+///         dbg!(*height);
+///     }
+/// }
+/// ```
+pub fn use_animated_with_clock<'a, T>(
+    cx: ScopeState<'a>,
+    clock: impl Clock,
+    target: T,
+    duration: Duration,
+) -> Signal<'a, T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T> + Copy + PartialEq + Send + 'static,
+{
+    let out = use_mut(cx, || target);
+    let last_target = use_ref(cx, || Cell::new(target));
+    let state: &RefCell<Option<ClockState<T>>> = use_ref(cx, || RefCell::new(None));
+
+    let now = clock.now();
+    let mut state_cell = state.borrow_mut();
+
+    // Start a new animation from the current value when the target changes.
+    if target != last_target.get() {
+        last_target.set(target);
+
+        *state_cell = Some(ClockState {
+            from: *out,
+            to: target,
+            start: now,
+        });
+    }
+
+    if let Some(state) = &*state_cell {
+        let elapsed = now.saturating_sub(state.start);
+
+        if elapsed < duration {
+            let t = elapsed.as_secs_f32() / duration.as_secs_f32();
+            SignalMut::set(out, state.from + (state.to - state.from) * t);
+        } else {
+            SignalMut::set_if_neq(out, state.to);
+            *state_cell = None;
+        }
+    }
+
+    SignalMut::as_ref(out)
+}
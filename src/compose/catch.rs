@@ -1,8 +1,15 @@
 use super::CatchContext;
-use crate::{compose::Compose, data::Data, use_provider, Scope, Signal};
+use crate::{compose::Compose, data::Data, use_context, use_provider, Scope, Signal};
 use alloc::rc::Rc;
 use core::mem;
 
+#[cfg(feature = "std")]
+use super::UnwindContext;
+#[cfg(feature = "std")]
+use crate::{composer::Runtime, use_ref};
+#[cfg(feature = "std")]
+use core::cell::Cell;
+
 /// Create a composable that catches errors from its children.
 /// This will catch all errors from its descendants, until another `catch` is encountered.
 ///
@@ -45,10 +52,71 @@ pub fn catch<'a, C: Compose>(
 ) -> Catch<'a, C> {
     Catch {
         content,
-        f: Rc::new(on_error),
+        f: Rc::new(move |error| {
+            on_error(error);
+            Ok(())
+        }),
     }
 }
 
+/// Create a composable that catches errors of type `E` from its children.
+///
+/// Errors that are not of type `E` will be passed to the next parent [`catch`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Data)]
+/// struct A;
+///
+/// impl Compose for A {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {
+///         let _: i32 = "".parse().map_err(Error::new)?;
+///
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {
+///         catch(
+///             |error| {
+///                 dbg!(error);
+///             },
+///             catch_typed(
+///                 |error: ParseIntError| {
+///                     dbg!(error);
+///                 },
+///                 A,
+///             ),
+///         )
+///     }
+/// }
+/// ```
+pub fn catch_typed<'a, E, C>(on_error: impl Fn(E) + 'a, content: C) -> Catch<'a, C>
+where
+    E: core::error::Error + 'static,
+    C: Compose,
+{
+    Catch {
+        content,
+        f: Rc::new(move |error: Box<dyn core::error::Error>| {
+            let error = error.downcast::<E>()?;
+            on_error(*error);
+            Ok(())
+        }),
+    }
+}
+
+type CatchFn<'a> =
+    Rc<dyn Fn(Box<dyn core::error::Error>) -> Result<(), Box<dyn core::error::Error>> + 'a>;
+
 /// Error catch composable.
 ///
 /// See [`catch`] for more.
@@ -58,21 +126,155 @@ pub struct Catch<'a, C> {
     /// Content of this composable.
     content: C,
 
-    /// Function to handle errors.
-    f: Rc<dyn Fn(Box<dyn core::error::Error>) + 'a>,
+    /// Function to handle errors, returning any unhandled error.
+    f: CatchFn<'a>,
 }
 
 impl<C: Compose> Compose for Catch<'_, C> {
     fn compose(cx: Scope<Self>) -> impl Compose {
-        let f: &dyn Fn(Box<dyn core::error::Error>) = &*cx.me().f;
+        let parent_cx = use_context::<CatchContext>(&cx).ok().cloned();
+
+        let f = cx.me().f.clone();
 
         // Cast this function to the `'static` lifetime.
         // Safety: This function has a lifetime of `'a`, which is guaranteed to outlive this composables descendants.
-        let f: Rc<dyn Fn(Box<dyn core::error::Error>)> = unsafe { mem::transmute(f) };
+        let f: CatchFn<'static> = unsafe { mem::transmute(f) };
 
-        use_provider(&cx, move || CatchContext { f: f.clone() });
+        use_provider(&cx, move || {
+            CatchContext::new(move |error| {
+                // Pass any unhandled errors to the parent catch.
+                if let Err(error) = f(error) {
+                    if let Some(parent_cx) = &parent_cx {
+                        (parent_cx.f)(error)
+                    }
+                }
+            })
+        });
 
         // Safety: The content of this composable is only returned into the composition once.
         unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
     }
 }
+
+#[cfg(feature = "std")]
+type UnwindFn<'a> = Rc<dyn Fn(Box<dyn core::any::Any + Send>) + 'a>;
+
+/// Create a composable that catches panics from its children, composing `fallback` instead.
+///
+/// If a descendant panics while composing, `on_panic` is called with the panic payload,
+/// and `content` (along with its state) is dropped and replaced with `fallback`.
+/// This will catch all panics from its descendants, until another `catch_unwind` is encountered.
+/// Panics from this composable's own parent are not caught.
+///
+/// The default panic hook still runs (and prints the panic message) before `on_panic` is called.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Plugin {
+///     is_loaded: bool,
+/// }
+///
+/// impl Compose for Plugin {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         if !cx.me().is_loaded {
+///             panic!("Plugin failed to load.");
+///         }
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct Fallback;
+///
+/// impl Compose for Fallback {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {}
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {
+///         catch_unwind(
+///             |payload| {
+///                 dbg!(payload.downcast_ref::<&str>());
+///             },
+///             Fallback,
+///             Plugin { is_loaded: false },
+///         )
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn catch_unwind<'a, F, C>(
+    on_panic: impl Fn(Box<dyn core::any::Any + Send>) + 'a,
+    fallback: F,
+    content: C,
+) -> CatchUnwind<'a, F, C>
+where
+    F: Compose,
+    C: Compose,
+{
+    CatchUnwind {
+        content,
+        fallback,
+        f: Rc::new(on_panic),
+    }
+}
+
+/// Panic catch composable.
+///
+/// See [`catch_unwind`] for more.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Data)]
+#[actuate(path = "crate")]
+pub struct CatchUnwind<'a, F, C> {
+    /// Content of this composable.
+    content: C,
+
+    /// Content to compose after a panic.
+    fallback: F,
+
+    /// Function to handle panics.
+    f: UnwindFn<'a>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Compose, C: Compose> Compose for CatchUnwind<'_, F, C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let has_panicked = use_ref(&cx, || Rc::new(Cell::new(false)));
+
+        let f = cx.me().f.clone();
+
+        // Cast this function to the `'static` lifetime.
+        // Safety: This function has a lifetime of `'a`, which is guaranteed to outlive this composables descendants.
+        let f: UnwindFn<'static> = unsafe { mem::transmute(f) };
+
+        let key = Runtime::current().current_key.get();
+        use_provider(&cx, || {
+            let has_panicked = has_panicked.clone();
+            UnwindContext {
+                f: Rc::new(move |payload| {
+                    f(payload);
+
+                    // Re-compose this composable to replace the content with its fallback.
+                    has_panicked.set(true);
+                    Runtime::current().queue(key);
+                }),
+            }
+        });
+
+        let has_panicked = has_panicked.get();
+
+        // Safety: Each mapped value is only returned once.
+        (
+            (!has_panicked).then(|| unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }),
+            has_panicked.then(|| unsafe { Signal::map_unchecked(cx.me(), |me| &me.fallback) }),
+        )
+    }
+}
@@ -1,5 +1,7 @@
 use super::{drop_node, AnyCompose, Node, Runtime};
-use crate::{compose::Compose, use_ref, Scope, ScopeData};
+use crate::{
+    compose::Compose, composer::ComposePtr, data::Data, use_ref, Scope, ScopeData, ScopeState,
+};
 use alloc::rc::Rc;
 use core::{
     any::TypeId,
@@ -74,27 +76,7 @@ impl Compose for DynCompose<'_> {
 
         let rt = Runtime::current();
 
-        if let Some(state) = state.get() {
-            let compose: &mut dyn AnyCompose = unsafe { &mut *cx.me().compose.get() }
-                .as_deref_mut()
-                .unwrap();
-            let mut compose: Box<dyn AnyCompose> = unsafe { mem::transmute(compose) };
-            let data_id = compose.data_id();
-
-            if data_id == state.data_id {
-                {
-                    let nodes = rt.nodes.borrow();
-                    let mut last = nodes[state.key].compose.borrow_mut();
-                    unsafe { compose.reborrow(last.as_ptr_mut()) };
-                }
-
-                rt.queue(state.key)
-            } else {
-                let mut nodes = rt.nodes.borrow_mut();
-                drop_node(&mut nodes, state.key);
-            }
-        }
-
+        // Move the new content out of this composable (this is `None` if this scope is re-composed without a new `DynCompose`).
         let Some(compose) = unsafe { &mut *cx.me().compose.get() }.take() else {
             if let Some(state) = state.get() {
                 rt.queue(state.key)
@@ -102,6 +84,27 @@ impl Compose for DynCompose<'_> {
 
             return;
         };
+
+        if let Some(state) = state.get() {
+            if compose.data_id() == state.data_id {
+                // Replace the content of the existing child, keeping its state.
+                // Its descendants are composed after it, so they never borrow the last content once it's dropped.
+                let compose: Box<dyn AnyCompose> = unsafe { mem::transmute(compose) };
+                let last = mem::replace(
+                    &mut *rt.nodes.borrow()[state.key].compose.borrow_mut(),
+                    ComposePtr::Boxed(compose),
+                );
+                drop(last);
+
+                rt.queue(state.key);
+                return;
+            }
+
+            // The type of the content has changed, so its state is dropped.
+            drop_node(&mut rt.nodes.borrow_mut(), state.key);
+        }
+
+        // Safety: The content is guaranteed to live as long as its node, which is replaced or dropped along with this scope.
         let compose: Box<dyn AnyCompose> = unsafe { mem::transmute(compose) };
         let data_id = compose.data_id();
 
@@ -111,7 +114,7 @@ impl Compose for DynCompose<'_> {
             scope: ScopeData::default(),
             parent: Some(rt.current_key.get()),
             children: RefCell::new(Vec::new()),
-            child_idx: 0,
+            child_idx: Cell::new(0),
         }));
         state.set(Some(DynComposeState { key, data_id }));
 
@@ -135,3 +138,159 @@ impl Compose for DynCompose<'_> {
         rt.queue(key);
     }
 }
+
+/// Boxed, type-erased composable.
+///
+/// Unlike [`DynCompose`], this can be borrowed by its parent, so a `Vec<BoxedCompose>` can be used to compose a list of heterogeneous composables.
+/// Each item keeps its state across re-compositions, as long as its type at that index stays the same.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use actuate::compose::BoxedCompose;
+///
+/// #[derive(Data)]
+/// struct A;
+///
+/// impl Compose for A {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {}
+/// }
+///
+/// #[derive(Data)]
+/// struct B;
+///
+/// impl Compose for B {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {}
+/// }
+///
+/// #[derive(Data)]
+/// struct Tabs;
+///
+/// impl Compose for Tabs {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {
+///         vec![BoxedCompose::new(A), BoxedCompose::new(B)]
+///     }
+/// }
+/// ```
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct BoxedCompose<'a> {
+    compose: Box<dyn AnyCompose + 'a>,
+}
+
+impl<'a> BoxedCompose<'a> {
+    /// Create a new boxed composable from its content.
+    pub fn new(content: impl Compose + 'a) -> Self {
+        Self {
+            compose: Box::new(content),
+        }
+    }
+
+    /// Get the type-erased content of this composable.
+    pub(crate) fn as_any_compose(&self) -> &(dyn AnyCompose + 'a) {
+        &*self.compose
+    }
+}
+
+unsafe impl Data for BoxedCompose<'_> {}
+
+impl Compose for BoxedCompose<'_> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        compose_boxed(&cx, core::iter::once(cx.me().as_any_compose()));
+    }
+
+    fn name() -> Option<std::borrow::Cow<'static, str>> {
+        None
+    }
+}
+
+impl<C: Compose> Compose for Vec<C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        // Each `BoxedCompose` item unwraps its own content, so its state is kept as long as its type stays the same.
+        compose_boxed(&cx, cx.me().iter().map(|item| item as &dyn AnyCompose));
+    }
+
+    fn name() -> Option<std::borrow::Cow<'static, str>> {
+        None
+    }
+}
+
+/// Type-erased `'static` composable.
+///
+/// For more see [`BoxedCompose`].
+pub type AnyComposable = BoxedCompose<'static>;
+
+/// Compose a list of composables, keeping the state of each item as long as its type stays the same.
+fn compose_boxed<'b>(
+    cx: ScopeState<'_>,
+    items: impl ExactSizeIterator<Item = &'b (dyn AnyCompose + 'b)>,
+) {
+    let states: &RefCell<Vec<DynComposeState>> = use_ref(cx, || RefCell::new(Vec::new()));
+    let mut states = states.borrow_mut();
+
+    let rt = Runtime::current();
+
+    // Remove any items past the end of this list.
+    if states.len() > items.len() {
+        let mut nodes = rt.nodes.borrow_mut();
+        for state in states.drain(items.len()..) {
+            drop_node(&mut nodes, state.key);
+        }
+    }
+
+    for (idx, item) in items.enumerate() {
+        // Safety: `item` is guaranteed to live as long as its node.
+        let data_id = item.data_id();
+        let ptr: *const dyn AnyCompose = unsafe { mem::transmute(item) };
+
+        let mut nodes = rt.nodes.borrow_mut();
+
+        if let Some(state) = states.get(idx).copied() {
+            if state.data_id == data_id {
+                *nodes[state.key].compose.borrow_mut() = ComposePtr::Ptr(ptr);
+
+                drop(nodes);
+
+                rt.queue(state.key);
+                continue;
+            }
+
+            // The type at this index has changed, so its state is dropped.
+            drop_node(&mut nodes, state.key);
+        }
+
+        let key = nodes.insert(Rc::new(Node {
+            compose: RefCell::new(ComposePtr::Ptr(ptr)),
+            scope: ScopeData::default(),
+            parent: Some(rt.current_key.get()),
+            children: RefCell::new(Vec::new()),
+            child_idx: Cell::new(idx),
+        }));
+
+        nodes
+            .get(rt.current_key.get())
+            .unwrap()
+            .children
+            .borrow_mut()
+            .push(key);
+
+        let child_state = &nodes[key].scope;
+        *child_state.contexts.borrow_mut() = cx.contexts.borrow().clone();
+        child_state
+            .contexts
+            .borrow_mut()
+            .values
+            .extend(cx.child_contexts.borrow().values.clone());
+
+        drop(nodes);
+
+        let state = DynComposeState { key, data_id };
+        if idx < states.len() {
+            states[idx] = state;
+        } else {
+            states.push(state);
+        }
+
+        rt.queue(key);
+    }
+}
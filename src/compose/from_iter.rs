@@ -1,7 +1,10 @@
 use super::{AnyCompose, Node, Runtime};
 use crate::{compose::Compose, data::Data, use_ref, Scope, ScopeData, Signal};
 use alloc::rc::Rc;
-use core::{cell::RefCell, mem};
+use core::{
+    cell::{Cell, RefCell},
+    mem,
+};
 use slotmap::DefaultKey;
 
 /// Create a composable from an iterator.
@@ -37,6 +40,52 @@ pub fn from_iter<'a, I, C>(
     iter: I,
     make_item: impl Fn(Signal<'a, I::Item>) -> C + 'a,
 ) -> FromIter<'a, I, I::Item, C>
+where
+    I: IntoIterator + Clone + Data,
+    I::Item: 'static,
+    C: Compose,
+{
+    FromIter {
+        iter,
+        make_item: Rc::new(move |_, item| make_item(item)),
+    }
+}
+
+/// Create a composable from an iterator, with the index of each item.
+///
+/// `make_item` will be called for each item and its index to produce a composable.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Row {
+///     is_even: bool,
+///     label: String,
+/// }
+///
+/// impl Compose for Row {
+///     fn compose(cx: Scope<Self>) -> impl Compose {}
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         compose::from_iter_indexed(vec!["A", "B", "C"], |idx, label| Row {
+///             is_even: idx % 2 == 0,
+///             label: label.to_string(),
+///         })
+///     }
+/// }
+/// ```
+pub fn from_iter_indexed<'a, I, C>(
+    iter: I,
+    make_item: impl Fn(usize, Signal<'a, I::Item>) -> C + 'a,
+) -> FromIter<'a, I, I::Item, C>
 where
     I: IntoIterator + Clone + Data,
     I::Item: 'static,
@@ -50,11 +99,11 @@ where
 
 /// Composable from an iterator.
 ///
-/// For more see [`from_iter`].
+/// For more see [`from_iter`] and [`from_iter_indexed`].
 #[must_use = "Composables do nothing unless composed or returned from other composables."]
 pub struct FromIter<'a, I, Item, C> {
     iter: I,
-    make_item: Rc<dyn Fn(Signal<'a, Item>) -> C + 'a>,
+    make_item: Rc<dyn Fn(usize, Signal<'a, Item>) -> C + 'a>,
 }
 
 impl<I, Item, C> Clone for FromIter<'_, I, Item, C>
@@ -109,10 +158,13 @@ where
             if state.key.is_none() {
                 let item_ref: &Item = &state.item;
                 let item_ref: &Item = unsafe { mem::transmute(item_ref) };
-                let compose = (cx.me().make_item)(Signal {
-                    value: item_ref,
-                    generation: &cx.generation as _,
-                });
+                let compose = (cx.me().make_item)(
+                    idx,
+                    Signal {
+                        value: item_ref,
+                        generation: &cx.generation as _,
+                    },
+                );
                 let any_compose: Box<dyn AnyCompose> = Box::new(compose);
                 let any_compose: Box<dyn AnyCompose> = unsafe { mem::transmute(any_compose) };
 
@@ -121,7 +173,7 @@ where
                     scope: ScopeData::default(),
                     parent: Some(rt.current_key.get()),
                     children: RefCell::new(Vec::new()),
-                    child_idx: idx,
+                    child_idx: Cell::new(idx),
                 }));
                 nodes
                     .get(rt.current_key.get())
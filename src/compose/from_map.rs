@@ -0,0 +1,102 @@
+use super::slotted;
+use crate::{compose::Compose, data::Data, Scope, Signal};
+use alloc::{collections::BTreeMap, rc::Rc};
+use core::{hash::Hash, mem};
+
+/// Create a composable from a map (or any iterator of key-value pairs), with one child for each entry.
+///
+/// `make_item` will be called for each key and value to produce a composable.
+/// Children are composed in key order (even for a `HashMap`), and the state of each child is kept by its key,
+/// so inserting or removing an entry only adds or drops that entry's child
+/// (see [`slotted`](super::slotted)).
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::collections::HashMap;
+///
+/// #[derive(Data)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// impl Compose for User {
+///     fn compose(cx: Scope<Self>) -> impl Compose {}
+/// }
+///
+/// #[derive(Data)]
+/// struct App {
+///     users: HashMap<u64, String>,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         compose::from_map(cx.me().users.clone(), |_id, name| User {
+///             name: (*name).clone(),
+///         })
+///     }
+/// }
+/// ```
+pub fn from_map<'a, K, V, C>(
+    map: impl IntoIterator<Item = (K, V)>,
+    make_item: impl Fn(&K, Signal<'a, V>) -> C + 'a,
+) -> FromMap<'a, K, V, C>
+where
+    K: Ord + Hash + Clone + 'static,
+    V: 'static,
+    C: Compose,
+{
+    FromMap {
+        map: map.into_iter().collect(),
+        make_item: Rc::new(make_item),
+    }
+}
+
+/// Composable from a map.
+///
+/// For more see [`from_map`].
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct FromMap<'a, K, V, C> {
+    map: BTreeMap<K, V>,
+    make_item: MakeItem<'a, K, V, C>,
+}
+
+type MakeItem<'a, K, V, C> = Rc<dyn Fn(&K, Signal<'a, V>) -> C + 'a>;
+
+// Safety: `K` and `V` are `'static`, so the map can't borrow from composition state.
+unsafe impl<K: 'static, V: 'static, C: Data> Data for FromMap<'_, K, V, C> {}
+
+impl<'a, K, V, C> Compose for FromMap<'a, K, V, C>
+where
+    K: Ord + Hash + Clone + 'static,
+    V: 'static,
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let children: Vec<(K, C)> = cx
+            .me()
+            .map
+            .iter()
+            .map(|(key, value)| {
+                // Safety: Each value is borrowed from `cx.me()`, which outlives this composable's children.
+                let value: &'a V = unsafe { mem::transmute(value) };
+
+                let item = (cx.me().make_item)(
+                    key,
+                    Signal {
+                        value,
+                        generation: &cx.generation as _,
+                    },
+                );
+                (key.clone(), item)
+            })
+            .collect();
+
+        slotted(children)
+    }
+
+    fn name() -> Option<alloc::borrow::Cow<'static, str>> {
+        None
+    }
+}
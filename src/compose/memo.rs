@@ -1,16 +1,93 @@
 use super::{use_node, AnyCompose, Runtime};
-use crate::{compose::Compose, composer::ComposePtr, data::Data, use_ref, Scope};
+use crate::{
+    compose::Compose, composer::ComposePtr, data::Data, use_ref, Generational, Map, Scope, Signal,
+    SignalMut,
+};
 use alloc::borrow::Cow;
 use core::{cell::RefCell, mem};
 
+/// Memoizable dependency.
+///
+/// This is implemented for any `Clone + PartialEq` type (compared by value),
+/// and for [`Signal`], [`Map`], and [`SignalMut`] (compared by generation, without cloning or comparing their value).
+pub trait Memoize {
+    /// Memoized value, compared to the last memoized value to check for changes.
+    type Value: PartialEq + 'static;
+
+    /// Get the memoized value of this dependency.
+    fn memoize(&self) -> Self::Value;
+}
+
+impl<T: Clone + PartialEq + 'static> Memoize for T {
+    type Value = T;
+
+    fn memoize(&self) -> Self::Value {
+        self.clone()
+    }
+}
+
+impl<T> Memoize for Signal<'_, T> {
+    type Value = u64;
+
+    fn memoize(&self) -> Self::Value {
+        self.generation()
+    }
+}
+
+impl<T> Memoize for Map<'_, T> {
+    type Value = u64;
+
+    fn memoize(&self) -> Self::Value {
+        self.generation()
+    }
+}
+
+impl<T> Memoize for SignalMut<'_, T> {
+    type Value = u64;
+
+    fn memoize(&self) -> Self::Value {
+        self.generation()
+    }
+}
+
 /// Create a new memoized composable.
 ///
-/// The content of the memoized composable is only re-composed when the dependency changes.
+/// The content of the memoized composable is only re-composed when the dependency changes (see [`Memoize`]).
 ///
 /// Children of this `Memo` may still be re-composed if their state has changed.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct List<'a> {
+///     items: Signal<'a, Vec<String>>,
+/// }
+///
+/// impl Compose for List<'_> {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         dbg!(cx.me().items.len());
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let items = use_mut(&cx, Vec::new);
+///
+///         // Only re-compose the list when `items` is updated, without cloning it.
+///         let items = SignalMut::as_ref(items);
+///         memo(items, List { items })
+///     }
+/// }
+/// ```
 pub fn memo<D, C>(dependency: D, content: C) -> Memo<D, C>
 where
-    D: Data + Clone + PartialEq + 'static,
+    D: Data + Memoize,
     C: Compose,
 {
     Memo {
@@ -32,7 +109,7 @@ pub struct Memo<T, C> {
 
 impl<T, C> Compose for Memo<T, C>
 where
-    T: Clone + Data + PartialEq + 'static,
+    T: Data + Memoize,
     C: Compose,
 {
     fn compose(cx: Scope<Self>) -> impl Compose {
@@ -45,14 +122,21 @@ where
         let last = use_ref(&cx, RefCell::default);
         let mut last = last.borrow_mut();
 
+        let value = cx.me().dependency.memoize();
+
         if let Some(last) = &mut *last {
-            if cx.me().dependency != *last {
-                *last = cx.me().dependency.clone();
+            if value != *last {
+                *last = value;
 
                 rt.queue(key);
+            } else {
+                rt.skipped_count.set(rt.skipped_count.get() + 1);
+
+                #[cfg(feature = "tracing")]
+                tracing::trace!("Skip: {}", C::name().as_deref().unwrap_or("<anonymous>"));
             }
         } else {
-            *last = Some(cx.me().dependency.clone());
+            *last = Some(value);
 
             rt.queue(key);
         }
@@ -13,19 +13,35 @@ use core::{
 use slotmap::{DefaultKey, SlotMap};
 
 mod catch;
-pub use self::catch::{catch, Catch};
+pub use self::catch::{catch, catch_typed, Catch};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use self::catch::{catch_unwind, CatchUnwind};
 
 mod dyn_compose;
-pub use self::dyn_compose::{dyn_compose, DynCompose};
+pub use self::dyn_compose::{dyn_compose, AnyComposable, BoxedCompose, DynCompose};
 
 mod from_fn;
 pub use self::from_fn::{from_fn, FromFn};
 
 mod from_iter;
-pub use self::from_iter::{from_iter, FromIter};
+pub use self::from_iter::{from_iter, from_iter_indexed, FromIter};
+
+mod from_map;
+pub use self::from_map::{from_map, FromMap};
 
 mod memo;
-pub use self::memo::{memo, Memo};
+pub use self::memo::{memo, Memo, Memoize};
+
+mod retained;
+pub use self::retained::{retained_result, RetainedResult};
+
+mod slotted;
+pub use self::slotted::{slotted, Slotted};
+
+mod suspense;
+pub use self::suspense::{suspense, Suspense};
 
 /// A composable function.
 ///
@@ -65,6 +81,48 @@ pub trait Compose: Data {
     }
 }
 
+/// Conversion into a [`Compose`].
+///
+/// This is implemented for every composable, so APIs that accept `impl IntoCompose` also accept any [`Compose`].
+/// With the `ui` feature, numbers and characters are also converted to text.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Item;
+///
+/// impl Compose for Item {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {}
+/// }
+///
+/// #[derive(Data)]
+/// struct List;
+///
+/// impl Compose for List {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {
+///         vec![Item, Item].into_compose()
+///     }
+/// }
+/// ```
+pub trait IntoCompose {
+    /// The composable this value converts into.
+    type Output: Compose;
+
+    /// Convert this value into a composable.
+    fn into_compose(self) -> Self::Output;
+}
+
+impl<C: Compose> IntoCompose for C {
+    type Output = C;
+
+    fn into_compose(self) -> Self::Output {
+        self
+    }
+}
+
 impl Compose for () {
     fn compose(cx: Scope<Self>) -> impl Compose {
         let _ = cx;
@@ -98,7 +156,7 @@ impl<C: Compose> Compose for Option<C> {
                     scope: ScopeData::default(),
                     parent: Some(rt.current_key.get()),
                     children: RefCell::new(Vec::new()),
-                    child_idx: 0,
+                    child_idx: Cell::new(0),
                 }));
                 child_key.set(Some(key));
 
@@ -138,6 +196,7 @@ fn drop_node(nodes: &mut SlotMap<DefaultKey, Rc<Node>>, key: DefaultKey) {
         parent.children.borrow_mut().retain(|&x| x != key);
     }
 
+    // Drop children before their parent.
     let children = node.children.borrow().clone();
     for key in children {
         drop_node(nodes, key)
@@ -207,7 +266,7 @@ impl<C: Compose> Compose for Result<C, Error> {
                         scope: ScopeData::default(),
                         parent: Some(rt.current_key.get()),
                         children: RefCell::new(Vec::new()),
-                        child_idx: 0,
+                        child_idx: Cell::new(0),
                     }));
                     child_key.set(Some(key));
 
@@ -235,7 +294,7 @@ impl<C: Compose> Compose for Result<C, Error> {
             Err(error) => {
                 let mut nodes = rt.nodes.borrow_mut();
 
-                if let Some(key) = child_key.get() {
+                if let Some(key) = child_key.take() {
                     drop_node(&mut nodes, key);
                 }
 
@@ -255,6 +314,12 @@ impl CatchContext {
     }
 }
 
+/// Context for [`catch_unwind`] to handle panics from descendants.
+#[cfg(feature = "std")]
+pub(crate) struct UnwindContext {
+    pub(crate) f: Rc<dyn Fn(Box<dyn core::any::Any + Send>)>,
+}
+
 macro_rules! impl_tuples {
     ($($t:tt : $idx:tt),*) => {
         unsafe impl<$($t: Data),*> Data for ($($t,)*) {}
@@ -286,7 +351,11 @@ impl_tuples!(T1:0, T2:1, T3:2, T4:3, T5:4, T6:5);
 impl_tuples!(T1:0, T2:1, T3:2, T4:3, T5:4, T6:5, T7:6);
 impl_tuples!(T1:0, T2:1, T3:2, T4:3, T5:4, T6:5, T7:6, T8:7);
 
-fn use_node(cx: ScopeState, compose_ptr: ComposePtr, child_idx: usize) -> (DefaultKey, &Rc<Node>) {
+fn use_node(
+    cx: ScopeState<'_>,
+    compose_ptr: ComposePtr,
+    child_idx: usize,
+) -> (DefaultKey, &Rc<Node>) {
     let mut compose_ptr_cell = Some(compose_ptr);
 
     let (key, node) = use_ref(cx, || {
@@ -298,7 +367,7 @@ fn use_node(cx: ScopeState, compose_ptr: ComposePtr, child_idx: usize) -> (Defau
             scope: ScopeData::default(),
             parent: Some(rt.current_key.get()),
             children: RefCell::new(Vec::new()),
-            child_idx,
+            child_idx: Cell::new(child_idx),
         }));
 
         nodes
@@ -327,6 +396,58 @@ fn use_node(cx: ScopeState, compose_ptr: ComposePtr, child_idx: usize) -> (Defau
     (*key, node)
 }
 
+/// Tracing span of a composition, recording its duration on exit.
+#[cfg(feature = "tracing")]
+struct ComposeSpan {
+    span: tracing::span::EnteredSpan,
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl ComposeSpan {
+    fn enter<C: Compose>(state: &ScopeData) -> Self {
+        let rt = Runtime::current();
+        let key = rt.current_key.get();
+
+        // Nest this span under the span of the parent composable.
+        let nodes = rt.nodes.borrow();
+        let parent = nodes
+            .get(key)
+            .and_then(|node| node.parent)
+            .and_then(|parent_key| nodes.get(parent_key))
+            .and_then(|parent| {
+                parent
+                    .scope
+                    .span
+                    .borrow()
+                    .as_ref()
+                    .and_then(|span| span.id())
+            });
+
+        let span = tracing::trace_span!(
+            parent: parent,
+            "compose",
+            name = C::name().as_deref().unwrap_or_default(),
+            key = ?key,
+            duration_us = tracing::field::Empty,
+        );
+        *state.span.borrow_mut() = Some(span.clone());
+
+        Self {
+            span: span.entered(),
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for ComposeSpan {
+    fn drop(&mut self) {
+        self.span
+            .record("duration_us", self.start.elapsed().as_micros() as u64);
+    }
+}
+
 pub(crate) trait AnyCompose {
     fn data_id(&self) -> TypeId;
 
@@ -357,6 +478,9 @@ where
     }
 
     unsafe fn any_compose(&self, state: &ScopeData) {
+        #[cfg(feature = "tracing")]
+        let _span = ComposeSpan::enter::<C>(state);
+
         // Reset the hook index.
         state.hook_idx.set(0);
 
@@ -376,6 +500,8 @@ where
 
         let child_key_cell = use_ref(&cx, || Cell::new(None));
 
+        cx.own_hook_idx.set(cx.hook_idx.get());
+
         let rt = Runtime::current();
 
         if cell.is_none() {
@@ -405,7 +531,7 @@ where
                         scope: ScopeData::default(),
                         parent: Some(rt.current_key.get()),
                         children: RefCell::new(Vec::new()),
-                        child_idx: 0,
+                        child_idx: Cell::new(0),
                     }));
                     child_key_cell.set(Some(child_key));
 
@@ -0,0 +1,155 @@
+use super::{AnyCompose, CatchContext, Error};
+use crate::{
+    compose::Compose,
+    composer::{ComposePtr, Node, Runtime},
+    data::Data,
+    use_context, use_drop, use_ref, Scope, ScopeData,
+};
+use alloc::rc::Rc;
+use core::{
+    cell::{Cell, RefCell},
+    mem,
+};
+
+/// Create a composable that keeps the state of its `Ok` content while `result` is an `Err`.
+///
+/// Unlike composing a `Result` directly, which drops its content on an error and recreates it on the next `Ok`,
+/// this suspends the content while `result` is an `Err` and re-attaches it once `result` is `Ok` again.
+/// Errors are still passed to the nearest parent [`catch`](super::catch).
+///
+/// Suspended content isn't composed, so its hook state may be stale when it's re-attached.
+/// Updates queued by the content (or its descendants) while suspended are deferred,
+/// and the content is re-composed from its last state once `result` is `Ok` again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Feed {
+///     count: usize,
+/// }
+///
+/// impl Compose for Feed {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let scroll = use_mut(&cx, || 0.);
+///         SignalMut::set(scroll, 1.);
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App {
+///     input: String,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let feed = cx
+///             .me()
+///             .input
+///             .parse()
+///             .map(|count| Feed { count })
+///             .map_err(Error::new);
+///
+///         // Keep the feed's scroll position while the input is invalid.
+///         catch(
+///             |error| {
+///                 dbg!(error);
+///             },
+///             retained_result(feed),
+///         )
+///     }
+/// }
+/// ```
+pub fn retained_result<C: Compose>(result: Result<C, Error>) -> RetainedResult<C> {
+    RetainedResult { result }
+}
+
+/// Composable that keeps the state of its `Ok` content while its result is an `Err`.
+///
+/// For more see [`retained_result`].
+#[derive(Data)]
+#[actuate(path = "crate")]
+pub struct RetainedResult<C> {
+    result: Result<C, Error>,
+}
+
+impl<C: Compose> Compose for RetainedResult<C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let catch_cx = use_context::<CatchContext>(&cx).unwrap();
+
+        let child_key = use_ref(&cx, || Cell::new(None));
+
+        let rt = Runtime::current();
+
+        use_drop(&cx, move || {
+            if let Some(key) = child_key.get() {
+                Runtime::current().resume(key);
+            }
+        });
+
+        match &cx.me().result {
+            Ok(content) => {
+                if let Some(key) = child_key.get() {
+                    rt.resume(key);
+
+                    let mut nodes = rt.nodes.borrow_mut();
+                    let last = nodes.get_mut(key).unwrap();
+
+                    let ptr = content as *const dyn AnyCompose;
+                    let ptr: *const dyn AnyCompose = unsafe { mem::transmute(ptr) };
+
+                    *last.compose.borrow_mut() = ComposePtr::Ptr(ptr);
+
+                    drop(nodes);
+
+                    rt.queue(key);
+                } else {
+                    let mut nodes = rt.nodes.borrow_mut();
+                    let ptr: *const dyn AnyCompose =
+                        unsafe { mem::transmute(content as *const dyn AnyCompose) };
+                    let key = nodes.insert(Rc::new(Node {
+                        compose: RefCell::new(ComposePtr::Ptr(ptr)),
+                        scope: ScopeData::default(),
+                        parent: Some(rt.current_key.get()),
+                        children: RefCell::new(Vec::new()),
+                        child_idx: Cell::new(0),
+                    }));
+                    child_key.set(Some(key));
+
+                    nodes
+                        .get(rt.current_key.get())
+                        .unwrap()
+                        .children
+                        .borrow_mut()
+                        .push(key);
+
+                    let child_state = &nodes[key].scope;
+
+                    *child_state.contexts.borrow_mut() = cx.contexts.borrow().clone();
+                    child_state
+                        .contexts
+                        .borrow_mut()
+                        .values
+                        .extend(cx.child_contexts.borrow().values.clone());
+
+                    drop(nodes);
+
+                    rt.queue(key);
+                }
+            }
+            Err(error) => {
+                // Suspend the content, instead of dropping it, until the result is `Ok` again.
+                if let Some(key) = child_key.get() {
+                    rt.suspend(key);
+
+                    // The last content was dropped, so replace it until the next `Ok`.
+                    *rt.nodes.borrow()[key].compose.borrow_mut() = ComposePtr::Boxed(Box::new(()));
+                }
+
+                (catch_cx.f)((error.make_error)())
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use super::{drop_node, AnyCompose, Node, Runtime};
+use crate::{
+    compose::Compose, composer::ComposePtr, data::Data, use_ref, HashMap, Scope, ScopeData,
+};
+use alloc::rc::Rc;
+use core::{
+    any::TypeId,
+    cell::{Cell, RefCell},
+    hash::Hash,
+    mem,
+};
+use slotmap::DefaultKey;
+
+/// Create a composable from a list of children, each identified by a key.
+///
+/// Each child is placed in the slot of its position in `children`.
+/// The state of each child is kept by its key (as long as its type stays the same),
+/// so a child keeps its state when it moves to another slot.
+/// Children whose key is removed are dropped.
+///
+/// Keys should be unique: a repeated key composes a new child with fresh state.
+///
+/// Spawned entities keep the order they were first spawned in,
+/// so children that can move between slots should be positioned explicitly (e.g. with a grid placement).
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Card {
+///     title: String,
+/// }
+///
+/// impl Compose for Card {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_expanded = use_mut(&cx, || false);
+///         dbg!(*is_expanded);
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct Board {
+///     titles: Vec<String>,
+/// }
+///
+/// impl Compose for Board {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         compose::slotted(
+///             cx.me()
+///                 .titles
+///                 .iter()
+///                 .map(|title| {
+///                     (
+///                         title.clone(),
+///                         Card {
+///                             title: title.clone(),
+///                         },
+///                     )
+///                 })
+///                 .collect(),
+///         )
+///     }
+/// }
+/// ```
+pub fn slotted<K, C>(children: Vec<(K, C)>) -> Slotted<K, C>
+where
+    K: Hash + Eq + Clone + 'static,
+    C: Compose,
+{
+    Slotted { children }
+}
+
+/// Composable from a list of keyed children.
+///
+/// For more see [`slotted`].
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct Slotted<K, C> {
+    children: Vec<(K, C)>,
+}
+
+// Safety: `K` is `'static`, so keys can't borrow from composition state.
+unsafe impl<K: 'static, C: Data> Data for Slotted<K, C> {}
+
+#[derive(Clone, Copy)]
+struct SlotState {
+    key: DefaultKey,
+    data_id: TypeId,
+}
+
+struct SlotStates<K> {
+    keyed: HashMap<K, SlotState>,
+    duplicates: Vec<DefaultKey>,
+}
+
+impl<K> Default for SlotStates<K> {
+    fn default() -> Self {
+        Self {
+            keyed: HashMap::default(),
+            duplicates: Vec::new(),
+        }
+    }
+}
+
+impl<K, C> Compose for Slotted<K, C>
+where
+    K: Hash + Eq + Clone + 'static,
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let states: &RefCell<SlotStates<K>> = use_ref(&cx, || RefCell::new(SlotStates::default()));
+        let SlotStates {
+            keyed: mut last_states,
+            duplicates,
+        } = mem::take(&mut *states.borrow_mut());
+
+        let mut next_states = SlotStates::default();
+        let mut slot_keys = Vec::with_capacity(cx.me().children.len());
+
+        let rt = Runtime::current();
+
+        // Children with repeated keys are never kept.
+        for key in duplicates {
+            drop_node(&mut rt.nodes.borrow_mut(), key);
+        }
+
+        for (idx, (slot_key, item)) in cx.me().children.iter().enumerate() {
+            let item = item as &dyn AnyCompose;
+            let data_id = item.data_id();
+
+            // Safety: `item` is guaranteed to live as long as its node.
+            let ptr: *const dyn AnyCompose = unsafe { mem::transmute(item) };
+
+            let mut nodes = rt.nodes.borrow_mut();
+
+            let is_duplicate = next_states.keyed.contains_key(slot_key);
+            let last = if is_duplicate {
+                None
+            } else {
+                last_states.remove(slot_key)
+            };
+
+            let key = match last {
+                Some(state) if state.data_id == data_id => {
+                    *nodes[state.key].compose.borrow_mut() = ComposePtr::Ptr(ptr);
+                    state.key
+                }
+                last => {
+                    // The type of this child has changed, so its state is dropped.
+                    if let Some(state) = last {
+                        drop_node(&mut nodes, state.key);
+                    }
+
+                    let key = nodes.insert(Rc::new(Node {
+                        compose: RefCell::new(ComposePtr::Ptr(ptr)),
+                        scope: ScopeData::default(),
+                        parent: Some(rt.current_key.get()),
+                        children: RefCell::new(Vec::new()),
+                        child_idx: Cell::new(idx),
+                    }));
+
+                    let child_state = &nodes[key].scope;
+                    *child_state.contexts.borrow_mut() = cx.contexts.borrow().clone();
+                    child_state
+                        .contexts
+                        .borrow_mut()
+                        .values
+                        .extend(cx.child_contexts.borrow().values.clone());
+
+                    key
+                }
+            };
+
+            drop(nodes);
+
+            // Move this child to its new slot, so siblings are composed in slot order.
+            rt.set_child_idx(key, idx);
+
+            if is_duplicate {
+                next_states.duplicates.push(key);
+            } else {
+                next_states
+                    .keyed
+                    .insert(slot_key.clone(), SlotState { key, data_id });
+            }
+            slot_keys.push(key);
+
+            rt.queue(key);
+        }
+
+        let mut nodes = rt.nodes.borrow_mut();
+
+        // Drop any children whose key was removed.
+        for state in last_states.into_values() {
+            drop_node(&mut nodes, state.key);
+        }
+
+        // Order the children of this node by slot.
+        *nodes[rt.current_key.get()].children.borrow_mut() = slot_keys;
+
+        *states.borrow_mut() = next_states;
+    }
+
+    fn name() -> Option<alloc::borrow::Cow<'static, str>> {
+        None
+    }
+}
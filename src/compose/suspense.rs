@@ -0,0 +1,107 @@
+use crate::{
+    compose::Compose, data::Data, use_local_task, use_mut, use_recompose, Scope, Signal, SignalMut,
+};
+use core::{cell::Cell, future::Future};
+
+/// Create a composable that shows `fallback` until `future` resolves, then composes its output.
+///
+/// The future is polled as a local task (see [`use_local_task`](crate::use_local_task)),
+/// starting from the first composition.
+/// Once it resolves, the fallback is dropped (along with its state) and the output is composed in its place.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Profile {
+///     name: String,
+/// }
+///
+/// impl Compose for Profile {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         dbg!(&cx.me().name);
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct Loading;
+///
+/// impl Compose for Loading {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {}
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         suspense(Loading, async {
+///             Profile {
+///                 name: String::from("Matt"),
+///             }
+///         })
+///     }
+/// }
+/// ```
+pub fn suspense<C, F>(fallback: C, future: F) -> Suspense<C, F>
+where
+    C: Compose,
+    F: Future + 'static,
+    F::Output: Compose + 'static,
+{
+    Suspense {
+        fallback,
+        future: Cell::new(Some(future)),
+    }
+}
+
+/// Suspense composable.
+///
+/// For more see [`suspense`].
+pub struct Suspense<C, F> {
+    fallback: C,
+    future: Cell<Option<F>>,
+}
+
+// Safety: `F` is `'static`, so the future can't borrow from composition state.
+unsafe impl<C: Data, F: 'static> Data for Suspense<C, F> {}
+
+impl<C, F> Compose for Suspense<C, F>
+where
+    C: Compose,
+    F: Future + 'static,
+    F::Output: Compose + 'static,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let output = use_mut(&cx, || None);
+        let recompose = use_recompose(&cx);
+
+        use_local_task(&cx, move || {
+            let future = cx.me().future.take();
+
+            async move {
+                if let Some(future) = future {
+                    let value = future.await;
+
+                    // Safety: Local tasks are never polled during composition,
+                    // so nothing can be borrowing this value.
+                    unsafe { *SignalMut::as_ptr(output).as_ptr() = Some(value) };
+                    recompose();
+                }
+            }
+        });
+
+        let output = SignalMut::as_ref(output);
+        let is_ready = output.is_some();
+
+        // Safety: Each mapped value is only returned once.
+        (
+            (!is_ready).then(|| unsafe { Signal::map_unchecked(cx.me(), |me| &me.fallback) }),
+            is_ready.then(|| unsafe {
+                Signal::map_unchecked(output, |output| output.as_ref().unwrap())
+            }),
+        )
+    }
+}
@@ -2,6 +2,9 @@ use crate::{
     compose::{AnyCompose, CatchContext, Compose},
     ScopeData,
 };
+
+#[cfg(feature = "std")]
+use crate::compose::UnwindContext;
 use alloc::{collections::BTreeSet, rc::Rc, sync::Arc, task::Wake};
 use core::{
     any::TypeId,
@@ -22,6 +25,8 @@ use tokio::sync::RwLock;
 
 type RuntimeFuture = Pin<Box<dyn Future<Output = ()>>>;
 
+type PostCompose = (DefaultKey, Box<dyn FnOnce()>);
+
 pub(crate) enum ComposePtr {
     Boxed(Box<dyn AnyCompose>),
     Ptr(*const dyn AnyCompose),
@@ -45,7 +50,11 @@ impl AnyCompose for ComposePtr {
     unsafe fn reborrow(&mut self, ptr: *mut ()) {
         match self {
             ComposePtr::Boxed(compose) => compose.reborrow(ptr),
-            ComposePtr::Ptr(_) => {}
+            // Parents re-point pointer-backed children by storing a fresh pointer to their content
+            // on every compose, so these are never reborrowed.
+            ComposePtr::Ptr(_) => {
+                debug_assert!(false, "pointer-backed composables can't be reborrowed")
+            }
         }
     }
 
@@ -70,7 +79,7 @@ pub(crate) struct Node {
     pub(crate) scope: ScopeData<'static>,
     pub(crate) parent: Option<DefaultKey>,
     pub(crate) children: RefCell<Vec<DefaultKey>>,
-    pub(crate) child_idx: usize,
+    pub(crate) child_idx: Cell<usize>,
 }
 
 /// Runtime for a [`Composer`].
@@ -85,6 +94,9 @@ pub(crate) struct Runtime {
     /// Queue for updates that mutate the composition tree.
     pub(crate) update_queue: Rc<SegQueue<Box<dyn FnMut()>>>,
 
+    /// Functions to run after the current compose pass, with the key of the node that queued each one.
+    pub(crate) post_compose_queue: Rc<RefCell<Vec<PostCompose>>>,
+
     #[cfg(feature = "executor")]
     /// Update lock for shared tasks.
     pub(crate) lock: Arc<RwLock<()>>,
@@ -97,7 +109,27 @@ pub(crate) struct Runtime {
 
     pub(crate) root: DefaultKey,
 
+    /// Queue of nodes to compose, ordered parent-before-child.
+    ///
+    /// Nodes are ordered by their path of child indices from the root,
+    /// so siblings are always composed in declaration order (ascending `child_idx`).
+    /// Queueing the same node multiple times will only compose it once.
     pub(crate) pending: Rc<RefCell<BTreeSet<Pending>>>,
+
+    /// Queue of nodes to compose with [`Priority::Low`], composed after any high priority nodes.
+    pub(crate) pending_low: Rc<RefCell<BTreeSet<Pending>>>,
+
+    /// Number of nodes composed in the current pass.
+    pub(crate) composed_count: Rc<Cell<usize>>,
+
+    /// Number of nodes skipped by memoization in the current pass.
+    pub(crate) skipped_count: Rc<Cell<usize>>,
+
+    /// Nodes that are retained but not composed, along with their descendants.
+    pub(crate) suspended: Rc<RefCell<Vec<DefaultKey>>>,
+
+    /// Nodes queued while suspended (and whether each has [`Priority::Low`]), re-queued once resumed.
+    pub(crate) suspended_pending: Rc<RefCell<Vec<(Pending, bool)>>>,
 }
 
 impl Runtime {
@@ -115,6 +147,19 @@ impl Runtime {
         })
     }
 
+    /// Get the name of the composable currently being composed, if any.
+    pub(crate) fn current_name() -> Option<alloc::borrow::Cow<'static, str>> {
+        RUNTIME.with(|runtime| {
+            let runtime = runtime.try_borrow().ok()?;
+            let rt = runtime.as_ref()?;
+
+            let nodes = rt.nodes.try_borrow().ok()?;
+            let node = nodes.get(rt.current_key.get())?;
+            let compose = node.compose.try_borrow().ok()?;
+            compose.name()
+        })
+    }
+
     /// Enter this runtime, making it available to [`Runtime::current`].
     pub fn enter(&self) {
         RUNTIME.with(|runtime| {
@@ -142,15 +187,125 @@ impl Runtime {
         }
     }
 
+    /// Queue a function to run after the current compose pass completes.
+    ///
+    /// Queued functions run once every pending composable has been composed,
+    /// before any updates from [`Runtime::update`] are applied.
+    /// Functions queued by a composable that is dropped before the pass completes are discarded.
+    pub fn post_compose(&self, f: impl FnOnce() + 'static) {
+        self.post_compose_queue
+            .borrow_mut()
+            .push((self.current_key.get(), Box::new(f)));
+    }
+
+    /// Returns `true` if the node at `key` or any of its ancestors is suspended.
+    pub(crate) fn is_suspended(
+        &self,
+        nodes: &SlotMap<DefaultKey, Rc<Node>>,
+        key: DefaultKey,
+    ) -> bool {
+        let suspended = self.suspended.borrow();
+        if suspended.is_empty() {
+            return false;
+        }
+
+        let mut parent = Some(key);
+        while let Some(key) = parent {
+            if suspended.contains(&key) {
+                return true;
+            }
+            parent = nodes.get(key).and_then(|node| node.parent);
+        }
+
+        false
+    }
+
+    /// Suspend the node at `key` and its descendants, so they aren't composed until [`Runtime::resume`].
+    pub(crate) fn suspend(&self, key: DefaultKey) {
+        let mut suspended = self.suspended.borrow_mut();
+        if !suspended.contains(&key) {
+            suspended.push(key);
+        }
+    }
+
+    /// Resume the node at `key`, re-queueing any of its descendants that were queued while suspended.
+    pub(crate) fn resume(&self, key: DefaultKey) {
+        self.suspended.borrow_mut().retain(|&x| x != key);
+
+        let parked = mem::take(&mut *self.suspended_pending.borrow_mut());
+        let nodes = self.nodes.borrow();
+
+        let mut requeued = Vec::new();
+        for (pending, is_low) in parked {
+            // Discard nodes that were dropped while suspended.
+            if !nodes.contains_key(pending.key) {
+                continue;
+            }
+
+            if self.is_suspended(&nodes, pending.key) {
+                self.suspended_pending.borrow_mut().push((pending, is_low));
+            } else {
+                requeued.push((pending.key, is_low));
+            }
+        }
+        drop(nodes);
+
+        // Indices are re-computed, in case a parent re-ordered its children while suspended.
+        for (key, is_low) in requeued {
+            let pending = self.pending(key);
+            self.queue_pending(pending, is_low);
+        }
+    }
+
+    /// Move the node at `key` to `child_idx` among its siblings.
+    ///
+    /// Pending nodes are ordered by their indices, so any pending nodes in this node's subtree are re-queued.
+    pub(crate) fn set_child_idx(&self, key: DefaultKey, child_idx: usize) {
+        let nodes = self.nodes.borrow();
+        if nodes[key].child_idx.get() == child_idx {
+            return;
+        }
+
+        let is_in_subtree = |mut parent| loop {
+            if parent == key {
+                return true;
+            }
+
+            match nodes.get(parent).and_then(|node| node.parent) {
+                Some(next) => parent = next,
+                None => return false,
+            }
+        };
+
+        let mut requeued = Vec::new();
+        for (pending, is_low) in [(&self.pending, false), (&self.pending_low, true)] {
+            pending.borrow_mut().retain(|pending| {
+                let is_moved = is_in_subtree(pending.key);
+                if is_moved {
+                    requeued.push((pending.key, is_low));
+                }
+                !is_moved
+            });
+        }
+
+        nodes[key].child_idx.set(child_idx);
+        drop(nodes);
+
+        for (key, is_low) in requeued {
+            let pending = self.pending(key);
+            self.queue_pending(pending, is_low);
+        }
+    }
+
     pub fn pending(&self, key: DefaultKey) -> Pending {
         let nodes = self.nodes.borrow();
         let node = nodes[key].clone();
 
-        let mut indices = vec![node.child_idx];
+        let mut indices = vec![node.child_idx.get()];
         let mut parent = node.parent;
 
         while let Some(key) = parent {
-            indices.push(nodes.get(key).unwrap().child_idx);
+            indices.push(nodes.get(key).unwrap().child_idx.get());
             parent = nodes.get(key).unwrap().parent;
         }
 
@@ -160,11 +315,47 @@ impl Runtime {
     }
 
     pub fn queue(&self, key: DefaultKey) {
+        self.queue_with_priority(key, Priority::High)
+    }
+
+    /// Queue a node to be composed with a [`Priority`].
+    ///
+    /// Nodes are never composed before their pending ancestors, even if the ancestor has a lower priority.
+    pub fn queue_with_priority(&self, key: DefaultKey, priority: Priority) {
         let pending = self.pending(key);
-        self.pending.borrow_mut().insert(pending);
+        self.queue_pending(pending, priority == Priority::Low);
+    }
+
+    fn queue_pending(&self, pending: Pending, is_low: bool) {
+        if is_low {
+            if !self.pending.borrow().contains(&pending) {
+                self.pending_low.borrow_mut().insert(pending);
+            }
+        } else {
+            self.pending_low.borrow_mut().remove(&pending);
+            self.pending.borrow_mut().insert(pending);
+        }
     }
 }
 
+/// Priority of an update to the composition.
+///
+/// Content queued with a high priority is composed before content queued with a low priority.
+/// To prevent starvation, at most [`MAX_HIGH_PRIORITY_STREAK`] high priority nodes
+/// are composed in a row while low priority content is waiting.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// High priority (e.g. updates from user input).
+    #[default]
+    High,
+
+    /// Low priority (e.g. updates from background tasks).
+    Low,
+}
+
+/// Maximum number of high [`Priority`] nodes to compose in a row while low priority nodes are pending.
+pub const MAX_HIGH_PRIORITY_STREAK: usize = 64;
+
 thread_local! {
     static RUNTIME: RefCell<Option<Runtime>> = const { RefCell::new(None) };
 }
@@ -200,6 +391,58 @@ impl PartialEq for TryComposeError {
     }
 }
 
+/// Progress of a composition from [`Composer::compose_budgeted`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComposeProgress {
+    /// All pending updates have been composed.
+    Complete,
+
+    /// The budget was reached before all pending updates could be composed.
+    Incomplete,
+}
+
+/// Memory and performance statistics of a [`Composer`].
+///
+/// This can be created with [`Composer::stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComposerStats {
+    /// Number of live nodes in the composition tree.
+    pub node_count: usize,
+
+    /// Total number of hooks stored across all scopes.
+    pub total_hooks: usize,
+
+    /// Number of live tasks.
+    pub task_count: usize,
+
+    /// Number of nodes composed in the last completed pass.
+    pub composed_count: usize,
+
+    /// Number of nodes skipped by memoization (e.g. [`Memo`](crate::compose::Memo)) in the last completed pass.
+    pub skipped_count: usize,
+}
+
+/// Information about a node in the composition tree.
+///
+/// This is passed to the visitor of [`Composer::walk`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeInfo {
+    /// Key of this node.
+    pub key: DefaultKey,
+
+    /// Key of this node's parent, or `None` for the root node.
+    pub parent: Option<DefaultKey>,
+
+    /// Name of this node's composable, or `None` for anonymous composables (like tuples).
+    pub name: Option<alloc::borrow::Cow<'static, str>>,
+
+    /// Index of this node in its parent's children.
+    pub child_idx: usize,
+
+    /// Number of hooks stored in this node's scope.
+    pub hook_count: usize,
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub(crate) struct Pending {
     pub(crate) key: DefaultKey,
@@ -221,7 +464,11 @@ impl Ord for Pending {
             }
         }
 
-        self.indices.len().cmp(&other.indices.len())
+        // Different nodes can share indices (e.g. a dropped node that's still queued and its replacement).
+        self.indices
+            .len()
+            .cmp(&other.indices.len())
+            .then_with(|| self.key.cmp(&other.key))
     }
 }
 
@@ -264,6 +511,11 @@ pub struct Composer {
     task_queue: Arc<SegQueue<DefaultKey>>,
     update_queue: Rc<SegQueue<Box<dyn FnMut()>>>,
     is_initial: bool,
+    last_pass: (usize, usize),
+    high_priority_streak: usize,
+
+    /// Error from the last composed node that wasn't handled by a [`Catch`](crate::compose::Catch) composable.
+    error_cell: Rc<Cell<Option<Box<dyn Error>>>>,
 }
 
 impl Composer {
@@ -281,14 +533,25 @@ impl Composer {
             scope: ScopeData::default(),
             parent: None,
             children: RefCell::new(Vec::new()),
-            child_idx: 0,
+            child_idx: Cell::new(0),
         }));
 
+        // Provide the root catch context once, as descendants keep the contexts they were created with.
+        let error_cell = Rc::new(Cell::new(None));
+        let error_cell_handle = error_cell.clone();
+        nodes[root_key].scope.contexts.borrow_mut().values.insert(
+            TypeId::of::<CatchContext>(),
+            Rc::new(CatchContext::new(move |error| {
+                error_cell_handle.set(Some(error));
+            })),
+        );
+
         Self {
             rt: Runtime {
                 tasks: Rc::new(RefCell::new(SlotMap::new())),
                 task_queue: task_queue.clone(),
                 update_queue: update_queue.clone(),
+                post_compose_queue: Rc::new(RefCell::new(Vec::new())),
                 waker: RefCell::new(None),
                 #[cfg(feature = "executor")]
                 lock,
@@ -296,13 +559,57 @@ impl Composer {
                 current_key: Rc::new(Cell::new(root_key)),
                 root: root_key,
                 pending: Rc::new(RefCell::new(BTreeSet::new())),
+                pending_low: Rc::new(RefCell::new(BTreeSet::new())),
+                composed_count: Rc::new(Cell::new(0)),
+                skipped_count: Rc::new(Cell::new(0)),
+                suspended: Rc::new(RefCell::new(Vec::new())),
+                suspended_pending: Rc::new(RefCell::new(Vec::new())),
             },
             task_queue,
             update_queue,
             is_initial: true,
+            last_pass: (0, 0),
+            high_priority_streak: 0,
+            error_cell,
         }
     }
 
+    /// Provide a context value of type `T` to the content of this composer.
+    ///
+    /// This value will be available to [`use_context`](crate::use_context) in the root composable and all of its children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actuate::{composer::Composer, prelude::*};
+    ///
+    /// struct Config {
+    ///     name: &'static str,
+    /// }
+    ///
+    /// #[derive(Data)]
+    /// struct App;
+    ///
+    /// impl Compose for App {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let config = use_context::<Config>(&cx).unwrap();
+    ///         assert_eq!(config.name, "actuate");
+    ///     }
+    /// }
+    ///
+    /// let mut composer = Composer::new(App).with_context(Config { name: "actuate" });
+    /// composer.try_compose().unwrap();
+    /// ```
+    pub fn with_context<T: 'static>(self, value: T) -> Self {
+        let root = self.rt.nodes.borrow()[self.rt.root].clone();
+        root.scope
+            .contexts
+            .borrow_mut()
+            .values
+            .insert(TypeId::of::<T>(), Rc::new(value));
+        self
+    }
+
     /// Try to immediately compose the content in this composer.
     pub fn try_compose(&mut self) -> Result<(), TryComposeError> {
         let mut is_pending = true;
@@ -320,6 +627,141 @@ impl Composer {
         }
     }
 
+    /// Try to immediately compose at most `max_nodes` of the pending content in this composer.
+    ///
+    /// This can be used to spread a large composition across multiple frames.
+    /// Queued updates are only applied once all pending content is composed,
+    /// so the composition tree is never changed while partially composed.
+    pub fn compose_budgeted(
+        &mut self,
+        max_nodes: usize,
+    ) -> Result<ComposeProgress, TryComposeError> {
+        let mut is_pending = true;
+
+        for _ in 0..max_nodes {
+            let Some(res) = self.next() else {
+                return if is_pending {
+                    Err(TryComposeError::Pending)
+                } else {
+                    Ok(ComposeProgress::Complete)
+                };
+            };
+            res.map_err(TryComposeError::Error)?;
+
+            is_pending = false;
+        }
+
+        if !self.is_initial && !self.has_pending() {
+            // Apply any queued updates without composing another node.
+            let _ = self.next();
+
+            return Ok(ComposeProgress::Complete);
+        }
+
+        Ok(ComposeProgress::Incomplete)
+    }
+
+    /// Get the current memory and performance statistics of this composer.
+    ///
+    /// Periodically checking these statistics can help find scopes that are never dropped.
+    pub fn stats(&self) -> ComposerStats {
+        let nodes = self.rt.nodes.borrow();
+
+        ComposerStats {
+            node_count: nodes.len(),
+            // Safety: Hooks are only mutated during composition.
+            total_hooks: nodes
+                .values()
+                .map(|node| unsafe { &*node.scope.hooks.get() }.len())
+                .sum(),
+            task_count: self.rt.tasks.borrow().len(),
+            composed_count: self.last_pass.0,
+            skipped_count: self.last_pass.1,
+        }
+    }
+
+    /// Visit each node in the composition tree, in tree order (each parent before its children).
+    ///
+    /// This is the structured counterpart to this composer's [`Debug`](fmt::Debug) output,
+    /// and can be used to build an inspector for the composition.
+    /// The tree is read before `f` is called, so no borrows of the tree are held while visiting.
+    pub fn walk(&self, mut f: impl FnMut(NodeInfo)) {
+        let mut infos = Vec::new();
+
+        {
+            let nodes = self.rt.nodes.borrow();
+            let mut stack = vec![self.rt.root];
+
+            while let Some(key) = stack.pop() {
+                let Some(node) = nodes.get(key) else {
+                    continue;
+                };
+
+                infos.push(NodeInfo {
+                    key,
+                    parent: node.parent,
+                    name: node.compose.borrow().name(),
+                    child_idx: node.child_idx.get(),
+                    // Safety: Hooks are only mutated during composition.
+                    hook_count: unsafe { &*node.scope.hooks.get() }.len(),
+                });
+
+                // Descendants of suspended nodes can point to content that no longer exists.
+                if self.rt.suspended.borrow().contains(&key) {
+                    continue;
+                }
+
+                // Push children in reverse, so they're visited in order.
+                stack.extend(node.children.borrow().iter().rev());
+            }
+        }
+
+        for info in infos {
+            f(info);
+        }
+    }
+
+    /// Queue the node at `key` to be re-composed, along with its children.
+    ///
+    /// This can be used to re-compose a composable that depends on external data, without a [`SignalMut`](crate::SignalMut).
+    /// Node keys can be found with [`Composer::walk`].
+    /// If `key` doesn't refer to a live node (e.g. it was dropped), this does nothing.
+    pub fn invalidate(&mut self, key: DefaultKey) {
+        if !self.rt.nodes.borrow().contains_key(key) {
+            return;
+        }
+
+        self.rt.queue(key);
+
+        if let Some(waker) = &*self.rt.waker.borrow() {
+            waker.wake_by_ref();
+        }
+    }
+
+    /// Queue the root node to be re-composed, along with its children.
+    #[cfg(feature = "ecs")]
+    pub(crate) fn invalidate_root(&mut self) {
+        self.invalidate(self.rt.root);
+    }
+
+    /// Set the [`Waker`] to wake when this composer needs to be re-composed.
+    ///
+    /// This can be used to integrate with a custom event loop,
+    /// calling [`Composer::try_compose`] after each wake-up.
+    /// [`Composer::poll_compose`] will replace this waker with the waker of its [`Context`].
+    pub fn set_waker(&mut self, waker: Waker) {
+        *self.rt.waker.borrow_mut() = Some(waker);
+    }
+
+    /// Returns `true` if this composer has pending content, updates, or tasks,
+    /// meaning the next call to [`Composer::try_compose`] will make progress.
+    pub fn needs_recompose(&self) -> bool {
+        self.is_initial
+            || self.has_pending()
+            || !self.update_queue.is_empty()
+            || !self.task_queue.is_empty()
+    }
+
     /// Poll a composition of the content in this composer.
     pub fn poll_compose(&mut self, cx: &mut Context) -> Poll<Result<(), Box<dyn Error>>> {
         *self.rt.waker.borrow_mut() = Some(cx.waker().clone());
@@ -337,13 +779,188 @@ impl Composer {
     }
 }
 
+impl Composer {
+    /// Set the value of the [`use_mut`](crate::use_mut) hook at `hook_idx` (of the composable's own hooks) in the node at `path`,
+    /// queueing the node to be re-composed.
+    ///
+    /// # Panics
+    /// Panics if the node or hook doesn't exist, or if the hook isn't a `use_mut` of type `T`.
+    #[cfg(feature = "test-util")]
+    pub(crate) fn set_mut<T: 'static>(&mut self, path: &[usize], hook_idx: usize, value: T) {
+        let nodes = self.rt.nodes.borrow();
+
+        let mut key = self.rt.root;
+        for idx in path {
+            key = *nodes[key]
+                .children
+                .borrow()
+                .get(*idx)
+                .unwrap_or_else(|| panic!("No child at index {idx} in path {path:?}."));
+        }
+
+        let node = nodes[key].clone();
+        drop(nodes);
+
+        // Safety: Hooks are only accessed during composition, which can't happen while `self` is borrowed.
+        let state = unsafe { &*node.scope.hooks.get() }
+            .get::<crate::MutState<T>>(node.scope.own_hook_idx.get() + hook_idx);
+        let state = unsafe { &mut *state.as_ptr() };
+        state.value = value;
+        state.generation.set(state.generation.get() + 1);
+
+        self.rt.queue(key);
+    }
+
+    fn has_pending(&self) -> bool {
+        !self.rt.pending.borrow().is_empty() || !self.rt.pending_low.borrow().is_empty()
+    }
+
+    /// Pop the next pending node to compose, preferring high priority nodes.
+    fn pop_pending(&mut self) -> Option<Pending> {
+        loop {
+            let has_low = !self.rt.pending_low.borrow().is_empty();
+            let is_high_empty = self.rt.pending.borrow().is_empty();
+
+            let is_low =
+                has_low && (is_high_empty || self.high_priority_streak >= MAX_HIGH_PRIORITY_STREAK);
+            let pending = if is_low {
+                self.high_priority_streak = 0;
+                self.rt.pending_low.borrow_mut().pop_first()
+            } else {
+                if has_low {
+                    self.high_priority_streak += 1;
+                }
+                self.rt.pending.borrow_mut().pop_first()
+            }?;
+
+            // Skip nodes that were dropped after being queued.
+            let nodes = self.rt.nodes.borrow();
+            if !nodes.contains_key(pending.key) {
+                continue;
+            }
+
+            // Park suspended nodes until they're resumed.
+            if self.rt.is_suspended(&nodes, pending.key) {
+                self.rt
+                    .suspended_pending
+                    .borrow_mut()
+                    .push((pending, is_low));
+                continue;
+            }
+
+            // Compose any pending ancestor first (from either lane), so this node never reads stale data from its parents.
+            if let Some(ancestor) = self.take_pending_ancestor(&nodes, &pending) {
+                self.rt.queue_pending(pending, is_low);
+                return Some(ancestor);
+            }
+
+            return Some(pending);
+        }
+    }
+
+    /// Remove and return the pending ancestor of `pending` closest to the root, if any.
+    fn take_pending_ancestor(
+        &self,
+        nodes: &SlotMap<DefaultKey, Rc<Node>>,
+        pending: &Pending,
+    ) -> Option<Pending> {
+        let mut ancestors = Vec::new();
+        let mut parent = nodes[pending.key].parent;
+        while let Some(key) = parent {
+            ancestors.push(key);
+            parent = nodes[key].parent;
+        }
+
+        // The indices of each ancestor are a prefix of this node's indices.
+        for (depth, key) in ancestors.into_iter().rev().enumerate() {
+            let ancestor = Pending {
+                key,
+                indices: pending.indices.get(..=depth)?.to_vec(),
+            };
+
+            if self.rt.pending.borrow_mut().remove(&ancestor)
+                || self.rt.pending_low.borrow_mut().remove(&ancestor)
+            {
+                return Some(ancestor);
+            }
+        }
+
+        None
+    }
+}
+
+impl Composer {
+    /// Shut down this composer, dropping all of its content.
+    ///
+    /// Pending updates are discarded and local tasks are cancelled first,
+    /// then each node is dropped (running its [`use_drop`](crate::use_drop) callbacks) with children before their parents.
+    /// This is also done when a [`Composer`] is dropped, but calling this makes the point of shutdown explicit.
+    pub fn shutdown(mut self) {
+        self.shutdown_inner();
+    }
+
+    fn shutdown_inner(&mut self) {
+        let Some(root) = self.rt.nodes.borrow().get(self.rt.root).cloned() else {
+            return;
+        };
+
+        // Drop callbacks may access the current runtime.
+        self.rt.enter();
+
+        // Discard pending updates, as they mutate state that's about to be dropped.
+        while self.update_queue.pop().is_some() {}
+        self.rt.post_compose_queue.borrow_mut().clear();
+
+        // Cancel local tasks before dropping the state they borrow.
+        let tasks = mem::take(&mut *self.rt.tasks.borrow_mut());
+        drop(tasks);
+        while self.task_queue.pop().is_some() {}
+
+        drop_recursive(&self.rt, self.rt.root, root)
+    }
+}
+
 impl Drop for Composer {
     fn drop(&mut self) {
-        let node = self.rt.nodes.borrow()[self.rt.root].clone();
-        drop_recursive(&self.rt, self.rt.root, node)
+        self.shutdown_inner();
     }
 }
 
+/// Compose a node, catching any panics if it's inside a [`catch_unwind`](crate::compose::catch_unwind) composable.
+///
+/// # Safety
+/// The node's composable must be valid for the lifetime of its scope.
+unsafe fn compose_node(node: &Node) {
+    #[cfg(feature = "std")]
+    {
+        let unwind_cx = node
+            .scope
+            .contexts
+            .borrow()
+            .values
+            .get(&TypeId::of::<UnwindContext>())
+            .cloned();
+
+        if let Some(unwind_cx) = unwind_cx {
+            let unwind_cx = unwind_cx.downcast::<UnwindContext>().unwrap();
+
+            // Any borrows of the runtime are released while unwinding,
+            // and the panicked node is dropped when its `catch_unwind` re-composes (before its descendants).
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                node.compose.borrow().any_compose(&node.scope)
+            }));
+
+            if let Err(payload) = result {
+                (unwind_cx.f)(payload);
+            }
+            return;
+        }
+    }
+
+    node.compose.borrow().any_compose(&node.scope)
+}
+
+/// Drop a node, dropping its children (in order) before the node itself.
 fn drop_recursive(rt: &Runtime, key: DefaultKey, node: Rc<Node>) {
     let children = node.children.borrow().clone();
     for child_key in children {
@@ -360,27 +977,26 @@ impl Iterator for Composer {
     fn next(&mut self) -> Option<Self::Item> {
         self.rt.enter();
 
-        let error_cell = Rc::new(Cell::new(None));
-        let error_cell_handle = error_cell.clone();
-
-        let root = self.rt.nodes.borrow().get(self.rt.root).unwrap().clone();
-        root.scope.contexts.borrow_mut().values.insert(
-            TypeId::of::<CatchContext>(),
-            Rc::new(CatchContext::new(move |error| {
-                error_cell_handle.set(Some(error));
-            })),
-        );
-
         if !self.is_initial {
-            let key_cell = self.rt.pending.borrow_mut().pop_first();
+            let key_cell = self.pop_pending();
             if let Some(pending) = key_cell {
                 self.rt.current_key.set(pending.key);
 
                 let node = self.rt.nodes.borrow().get(pending.key).unwrap().clone();
+                self.rt.composed_count.set(self.rt.composed_count.get() + 1);
 
                 // Safety: `self.compose` is guaranteed to live as long as `self.scope_state`.
-                unsafe { node.compose.borrow().any_compose(&node.scope) };
+                unsafe { compose_node(&node) };
             } else {
+                // Run post-compose functions now that every pending node is composed.
+                let post_compose = mem::take(&mut *self.rt.post_compose_queue.borrow_mut());
+                for (key, f) in post_compose {
+                    if self.rt.nodes.borrow().contains_key(key) {
+                        self.rt.current_key.set(key);
+                        f();
+                    }
+                }
+
                 while let Some(key) = self.task_queue.pop() {
                     let waker = Waker::from(Arc::new(TaskWaker {
                         key,
@@ -398,18 +1014,24 @@ impl Iterator for Composer {
                     update();
                 }
 
+                // Complete this pass.
+                self.last_pass = (self.rt.composed_count.take(), self.rt.skipped_count.take());
+
                 return None;
             }
         } else {
             self.is_initial = false;
 
             self.rt.current_key.set(self.rt.root);
+            self.rt.composed_count.set(self.rt.composed_count.get() + 1);
+
+            let root = self.rt.nodes.borrow().get(self.rt.root).unwrap().clone();
 
             // Safety: `self.compose` is guaranteed to live as long as `self.scope_state`.
             unsafe { root.compose.borrow().any_compose(&root.scope) };
         }
 
-        Some(error_cell.take().map(Err).unwrap_or(Ok(())))
+        Some(self.error_cell.take().map(Err).unwrap_or(Ok(())))
     }
 }
 
@@ -417,7 +1039,12 @@ impl fmt::Debug for Composer {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut dbg_tuple = f.debug_tuple("Composer");
 
-        dbg_composer(&mut dbg_tuple, &self.rt.nodes.borrow(), self.rt.root);
+        dbg_composer(
+            &mut dbg_tuple,
+            &self.rt.nodes.borrow(),
+            &self.rt.suspended.borrow(),
+            self.rt.root,
+        );
 
         dbg_tuple.finish()
     }
@@ -426,6 +1053,7 @@ impl fmt::Debug for Composer {
 struct Field<'a> {
     name: &'a str,
     nodes: &'a SlotMap<DefaultKey, Rc<Node>>,
+    suspended: &'a [DefaultKey],
     children: &'a [DefaultKey],
 }
 
@@ -434,7 +1062,7 @@ impl fmt::Debug for Field<'_> {
         let mut dbg_tuple = f.debug_tuple(self.name);
 
         for child_key in self.children {
-            dbg_composer(&mut dbg_tuple, self.nodes, *child_key);
+            dbg_composer(&mut dbg_tuple, self.nodes, self.suspended, *child_key);
         }
 
         dbg_tuple.finish()
@@ -444,18 +1072,28 @@ impl fmt::Debug for Field<'_> {
 fn dbg_composer(
     dbg_tuple: &mut fmt::DebugTuple,
     nodes: &SlotMap<DefaultKey, Rc<Node>>,
+    suspended: &[DefaultKey],
     key: DefaultKey,
 ) {
     let node = &nodes[key];
+
+    // Descendants of suspended nodes can point to content that no longer exists.
+    let children = if suspended.contains(&key) {
+        Vec::new()
+    } else {
+        node.children.borrow().clone()
+    };
+
     if let Some(name) = node.compose.borrow().name() {
         dbg_tuple.field(&Field {
             name: &name,
             nodes,
-            children: &node.children.borrow(),
+            suspended,
+            children: &children,
         });
     } else {
-        for child_key in &*node.children.borrow() {
-            dbg_composer(dbg_tuple, nodes, *child_key);
+        for child_key in children {
+            dbg_composer(dbg_tuple, nodes, suspended, child_key);
         }
     }
 }
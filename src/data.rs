@@ -10,6 +10,47 @@
 //! - Are `'static`.
 //! - Are functions that take `'static` arguments and return a type that implements the [`Data`] trait.
 //!
+//! # Skipping fields
+//!
+//! Fields can be excluded from these checks with `#[data(skip)]`:
+//!
+//! ```
+//! use actuate::prelude::*;
+//!
+//! struct Handle(*const ());
+//!
+//! #[derive(Data)]
+//! struct A {
+//!     #[data(skip)]
+//!     handle: Handle,
+//! }
+//! ```
+//!
+//! This is unchecked: a skipped field must never contain a reference to composition state
+//! (or anything else borrowed), as [`Data`] is used to guarantee these references stay pinned.
+//! Skipping a field that borrows can lead to use-after-free and other undefined behavior.
+//!
+//! # Props builders
+//!
+//! `#[derive(Props)]` generates a builder for a composable's fields.
+//! Unset fields use their [`Default`] value, while fields marked with `#[props(required)]`
+//! must be set before calling `build` (or it will panic).
+//!
+//! ```
+//! use actuate::prelude::*;
+//!
+//! #[derive(Data, Props)]
+//! struct Counter {
+//!     #[props(required)]
+//!     start: i32,
+//!     step: i32,
+//! }
+//!
+//! let counter = Counter::builder().start(5).build();
+//! assert_eq!(counter.start, 5);
+//! assert_eq!(counter.step, 0);
+//! ```
+//!
 //! # Trait objects
 //!
 //! Trait objects can also borrow from state:
@@ -57,7 +98,7 @@
 use crate::{compose::DynCompose, HashMap};
 use core::{error::Error, future::Future, ops::Range, pin::Pin};
 
-pub use actuate_macros::{data, Data};
+pub use actuate_macros::{data, Data, Props};
 
 /// Composable data.
 ///
@@ -102,10 +143,14 @@ impl_data_for_std!(
 
 unsafe impl Data for &str {}
 
+unsafe impl Data for alloc::borrow::Cow<'_, str> {}
+
 unsafe impl<T: Data> Data for Vec<T> {}
 
 unsafe impl<T: Data, U: Data, S: 'static> Data for HashMap<T, U, S> {}
 
+unsafe impl<T: Data, U: Data> Data for alloc::collections::BTreeMap<T, U> {}
+
 unsafe impl<T: 'static> Data for &T {}
 
 unsafe impl<T: Data> Data for Option<T> {}
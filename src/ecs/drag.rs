@@ -0,0 +1,121 @@
+use super::Modifier;
+use crate::{data::Data, use_mut, use_ref, ScopeState, Signal, SignalMut};
+use bevy_ecs::prelude::Trigger;
+use bevy_math::Vec2;
+use bevy_picking::prelude::*;
+use bevy_utils::Instant;
+use std::{rc::Rc, sync::Mutex, time::Duration};
+
+/// Time after the last drag movement where a release is considered a flick.
+const FLICK_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Drag gesture state.
+///
+/// For more see [`use_drag`].
+#[derive(Clone)]
+pub struct DragGesture<'a> {
+    delta: Signal<'a, Option<Vec2>>,
+    velocity: Signal<'a, Vec2>,
+    modifier: Modifier<'a>,
+}
+
+unsafe impl Data for DragGesture<'_> {}
+
+impl<'a> DragGesture<'a> {
+    /// Get the distance dragged since the drag started, or `None` if this gesture isn't being dragged.
+    pub fn delta(&self) -> Signal<'a, Option<Vec2>> {
+        self.delta
+    }
+
+    /// Get the velocity (in logical pixels per second) of the last drag when it was released.
+    ///
+    /// This is zero if the pointer stopped moving before it was released.
+    pub fn velocity(&self) -> Signal<'a, Vec2> {
+        self.velocity
+    }
+
+    /// Get the modifier that attaches this gesture's observers to a composable.
+    pub fn modifier(&self) -> &Modifier<'a> {
+        &self.modifier
+    }
+}
+
+/// Use a drag gesture.
+///
+/// The returned [`DragGesture`] tracks the current drag delta and the release velocity (for flick gestures).
+/// Its modifier adds the [`DragStart`], [`Drag`], and [`DragEnd`] observers,
+/// and should be applied to the composable to drag.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Panel;
+///
+/// impl Compose for Panel {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let drag = use_drag(&cx);
+///         let offset = drag.delta().unwrap_or_default();
+///
+///         drag.modifier().apply(spawn(Node {
+///             left: Val::Px(offset.x),
+///             top: Val::Px(offset.y),
+///             ..Default::default()
+///         }))
+///     }
+/// }
+/// ```
+pub fn use_drag(cx: ScopeState<'_>) -> DragGesture<'_> {
+    let delta = use_mut(cx, || None);
+    let velocity = use_mut(cx, || Vec2::ZERO);
+
+    // Time and velocity of the last drag movement.
+    let sample: &Mutex<Option<(Instant, Vec2)>> = use_ref(cx, || Mutex::new(None));
+
+    let mut modifier = Modifier::default();
+    modifier.fns.push(Rc::new(move |spawn| {
+        spawn
+            .observe(move |_: Trigger<Pointer<DragStart>>| {
+                *sample.lock().unwrap() = None;
+                SignalMut::set(delta, Some(Vec2::ZERO));
+            })
+            .observe(move |trigger: Trigger<Pointer<Drag>>| {
+                let now = Instant::now();
+
+                let mut sample = sample.lock().unwrap();
+                let last_velocity = match *sample {
+                    Some((last, last_velocity)) => {
+                        let dt = now.duration_since(last).as_secs_f32();
+                        if dt > 0. {
+                            // Smooth out jitter between pointer events.
+                            (last_velocity + trigger.delta / dt) / 2.
+                        } else {
+                            last_velocity
+                        }
+                    }
+                    None => Vec2::ZERO,
+                };
+                *sample = Some((now, last_velocity));
+
+                SignalMut::set(delta, Some(trigger.distance));
+            })
+            .observe(move |_: Trigger<Pointer<DragEnd>>| {
+                let release_velocity = match sample.lock().unwrap().take() {
+                    Some((last, last_velocity)) if last.elapsed() <= FLICK_TIMEOUT => last_velocity,
+                    _ => Vec2::ZERO,
+                };
+
+                SignalMut::set(velocity, release_velocity);
+                SignalMut::set(delta, None);
+            })
+    }));
+
+    DragGesture {
+        delta: SignalMut::as_ref(delta),
+        velocity: SignalMut::as_ref(velocity),
+        modifier,
+    }
+}
@@ -2,7 +2,8 @@ use crate::{
     compose::Compose,
     composer::{Composer, Pending},
     data::Data,
-    use_callback, use_drop, use_provider, use_ref, Cow, Scope, ScopeState, Signal,
+    use_callback, use_drop, use_mut, use_provider, use_ref, Cow, Scope, ScopeState, Signal,
+    SignalMut,
 };
 use bevy_app::{App, Plugin};
 use bevy_ecs::{
@@ -14,15 +15,22 @@ use bevy_ecs::{
 };
 use bevy_utils::HashMap;
 use bevy_winit::{EventLoopProxy, EventLoopProxyWrapper, WakeUp};
-use core::fmt;
+use core::{
+    any::{Any, TypeId},
+    fmt,
+    marker::PhantomData,
+};
 use slotmap::{DefaultKey, SlotMap};
 use std::{
-    cell::{Cell, RefCell},
+    cell::{Cell, RefCell, UnsafeCell},
     collections::BTreeSet,
     mem, ptr,
     rc::Rc,
-    sync::Arc,
-    task::{Context, Wake, Waker},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
 };
 
 #[cfg(feature = "ui")]
@@ -31,9 +39,35 @@ use bevy_ui::prelude::*;
 #[cfg(feature = "picking")]
 use bevy_picking::prelude::*;
 
+#[cfg(feature = "input")]
+mod modal;
+#[cfg(feature = "input")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input")))]
+pub use self::modal::{use_escape, ModalContext};
+
+#[cfg(feature = "picking")]
+mod drag;
+#[cfg(feature = "picking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "picking")))]
+pub use self::drag::{use_drag, DragGesture};
+
+#[cfg(feature = "input")]
+mod shortcut;
+#[cfg(feature = "input")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input")))]
+pub use self::shortcut::{
+    shortcuts, use_key_bindings, use_shortcut, KeyCombo, ShortcutContext, Shortcuts,
+};
+
 mod spawn;
 pub use self::spawn::{spawn, Spawn};
 
+#[cfg(feature = "window")]
+mod window;
+#[cfg(feature = "window")]
+#[cfg_attr(docsrs, doc(cfg(feature = "window")))]
+pub use self::window::{use_breakpoint, use_window_size};
+
 macro_rules! impl_trait_for_tuples {
     ($t:tt) => {
         $t!();
@@ -58,10 +92,37 @@ impl Plugin for ActuatePlugin {
         };
 
         app.insert_non_send_resource(rt)
+            .add_event::<CompositionError>()
             .add_systems(bevy_app::prelude::Update, compose);
     }
 }
 
+/// Event sent when a [`Composition`] fails with an error.
+///
+/// Errors that aren't handled by a [`Catch`](crate::compose::Catch) composable
+/// are sent as this event after each frame.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// fn log_errors(mut events: EventReader<CompositionError>) {
+///     for event in events.read() {
+///         error!("Composition {:?} failed: {}", event.entity, event.error);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Event)]
+pub struct CompositionError {
+    /// Entity of the [`Composition`] that failed.
+    pub entity: Entity,
+
+    /// Message of the error that occurred.
+    pub error: String,
+}
+
 type UpdateFn = Box<dyn FnMut(&mut World)>;
 
 type WorldListenerFn = Rc<dyn Fn(&mut World)>;
@@ -69,6 +130,7 @@ type WorldListenerFn = Rc<dyn Fn(&mut World)>;
 struct Inner {
     world_ptr: *mut World,
     listeners: SlotMap<DefaultKey, WorldListenerFn>,
+    system_states: HashMap<TypeId, Rc<dyn Any>>,
     updates: Vec<UpdateFn>,
     commands: Rc<RefCell<CommandQueue>>,
 }
@@ -92,6 +154,11 @@ impl RuntimeContext {
     unsafe fn world_mut(&self) -> &'static mut World {
         &mut *self.inner.borrow().world_ptr
     }
+
+    /// Get the current world, or `None` if it's being dropped.
+    unsafe fn try_world_mut(&self) -> Option<&'static mut World> {
+        self.inner.borrow().world_ptr.as_mut()
+    }
 }
 
 thread_local! {
@@ -100,16 +167,31 @@ thread_local! {
 
 struct RuntimeComposer {
     composer: Composer,
+    is_active: Arc<AtomicBool>,
+    was_active: bool,
 }
 
 struct Runtime {
     composers: RefCell<HashMap<Entity, RuntimeComposer>>,
 }
 
+impl Drop for Runtime {
+    fn drop(&mut self) {
+        // This runtime is dropped with its world, so content dropped after this can't access the world.
+        RUNTIME_CONTEXT.with(|runtime_cx| {
+            if let Some(runtime_cx) = &*runtime_cx.borrow() {
+                runtime_cx.inner.borrow_mut().world_ptr = ptr::null_mut();
+            }
+        });
+    }
+}
+
 /// Composition of some composable content.
 pub struct Composition<C> {
     content: Option<C>,
     target: Option<Entity>,
+    child_index: Option<usize>,
+    is_active: Arc<AtomicBool>,
 }
 
 impl<C> Composition<C>
@@ -121,9 +203,33 @@ where
         Self {
             content: Some(content),
             target: None,
+            child_index: None,
+            is_active: Arc::new(AtomicBool::new(true)),
         }
     }
 
+    /// Returns `true` if this composition is active (default: `true`).
+    pub fn is_active(&self) -> bool {
+        self.is_active.load(Ordering::Relaxed)
+    }
+
+    /// Set whether this composition is active.
+    ///
+    /// Inactive compositions are not re-composed, but keep their state and spawned entities.
+    /// Updates made while inactive (e.g. from tasks or [`use_world`] listeners, which keep running)
+    /// are applied once the composition is re-activated, which also re-composes it once to sync.
+    pub fn set_active(&mut self, is_active: bool) {
+        self.is_active.store(is_active, Ordering::Relaxed);
+    }
+
+    /// Set whether this composition is active.
+    ///
+    /// For more see [`Composition::set_active`].
+    pub fn with_active(self, is_active: bool) -> Self {
+        self.is_active.store(is_active, Ordering::Relaxed);
+        self
+    }
+
     /// Get the target entity to spawn the composition into.
     ///
     /// If `None`, this will use the composition's parent (if any).
@@ -145,6 +251,28 @@ where
         self.target = Some(target);
         self
     }
+
+    /// Get the index to insert the composition's spawned entities at, among the target's existing children.
+    ///
+    /// If `None`, entities are inserted starting from the first child.
+    pub fn child_index(&self) -> Option<usize> {
+        self.child_index
+    }
+
+    /// Set the index to insert the composition's spawned entities at, among the target's existing children.
+    ///
+    /// If `None`, entities are inserted starting from the first child.
+    pub fn set_child_index(&mut self, child_index: Option<usize>) {
+        self.child_index = child_index;
+    }
+
+    /// Set the index to insert the composition's spawned entities at, among the target's existing children.
+    ///
+    /// If this index is past the target's last child, entities will be appended.
+    pub fn with_child_index(mut self, child_index: usize) -> Self {
+        self.child_index = Some(child_index);
+        self
+    }
 }
 
 impl<C> Component for Composition<C>
@@ -160,13 +288,22 @@ where
 
                 let content = composition.content.take().unwrap();
                 let target = composition.target.unwrap_or(entity);
+                let child_index = composition.child_index.unwrap_or_default();
+
+                let is_active = composition.is_active.clone();
 
                 let rt = world.non_send_resource_mut::<Runtime>();
 
                 rt.composers.borrow_mut().insert(
                     entity,
                     RuntimeComposer {
-                        composer: Composer::new(CompositionContent { content, target }),
+                        composer: Composer::new(CompositionContent {
+                            content,
+                            target,
+                            child_index,
+                        }),
+                        is_active,
+                        was_active: true,
                     },
                 );
             });
@@ -179,26 +316,34 @@ where
 struct CompositionContent<C> {
     content: C,
     target: Entity,
+    child_index: usize,
 }
 
 impl<C: Compose> Compose for CompositionContent<C> {
     fn compose(cx: Scope<Self>) -> impl Compose {
         use_provider(&cx, || SpawnContext {
             parent_entity: cx.me().target,
+            child_offset: cx.me().child_index,
             keys: RefCell::new(BTreeSet::new()),
         });
 
+        #[cfg(feature = "ui")]
+        use_provider(&cx, crate::ui::FocusContext::default);
+
         unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
     }
 }
 
 struct RuntimeWaker {
-    proxy: EventLoopProxy<WakeUp>,
+    /// Proxy to wake the winit event loop, or `None` for apps without one (which are updated manually).
+    proxy: Option<EventLoopProxy<WakeUp>>,
 }
 
 impl Wake for RuntimeWaker {
     fn wake(self: Arc<Self>) {
-        self.proxy.send_event(WakeUp).unwrap();
+        if let Some(proxy) = &self.proxy {
+            proxy.send_event(WakeUp).unwrap();
+        }
     }
 }
 
@@ -209,6 +354,7 @@ fn compose(world: &mut World) {
             inner: Rc::new(RefCell::new(Inner {
                 world_ptr: ptr::null_mut(),
                 listeners: SlotMap::new(),
+                system_states: HashMap::new(),
                 updates: Vec::new(),
                 commands: Rc::new(RefCell::new(CommandQueue::default())),
             })),
@@ -233,21 +379,39 @@ fn compose(world: &mut World) {
     rt.commands.borrow_mut().apply(world);
     drop(rt);
 
-    let proxy = (*world
+    let proxy = world
         .get_resource::<EventLoopProxyWrapper<WakeUp>>()
-        .unwrap())
-    .clone();
+        .map(|proxy| (**proxy).clone());
     let rt = &mut *world.non_send_resource_mut::<Runtime>();
     let mut composers = rt.composers.borrow_mut();
-    for rt_composer in composers.values_mut() {
+    let mut errors = Vec::new();
+    for (entity, rt_composer) in composers.iter_mut() {
+        // Skip inactive compositions, re-composing once when they're re-activated.
+        let is_active = rt_composer.is_active.load(Ordering::Relaxed);
+        if !is_active {
+            rt_composer.was_active = false;
+            continue;
+        }
+        if !rt_composer.was_active {
+            rt_composer.was_active = true;
+            rt_composer.composer.invalidate_root();
+        }
+
         let waker = Waker::from(Arc::new(RuntimeWaker {
             proxy: proxy.clone(),
         }));
         let mut cx = Context::from_waker(&waker);
 
-        // TODO handle composition error.
-        let _ = rt_composer.composer.poll_compose(&mut cx);
+        if let Poll::Ready(Err(error)) = rt_composer.composer.poll_compose(&mut cx) {
+            errors.push(CompositionError {
+                entity: *entity,
+                error: error.to_string(),
+            });
+        }
     }
+    drop(composers);
+
+    world.send_event_batch(errors);
 }
 
 /// A function that takes a [`SystemParam`] as input.
@@ -386,7 +550,7 @@ where
 {
     let system_state_cell = use_ref(cx, || RefCell::new(None));
 
-    let f: Rc<dyn Fn(&'static mut World)> = use_callback(cx, move |world: &'static mut World| {
+    use_world_listener(cx, move |world| {
         let mut system_state_cell = system_state_cell.borrow_mut();
         let system_state =
             system_state_cell.get_or_insert_with(|| SystemState::<F::Param>::new(world));
@@ -395,8 +559,231 @@ where
         with_world.run((), params);
 
         system_state.apply(world);
+    });
+}
+
+/// Use one or more [`SystemParam`]s from the ECS world, sharing their state with other composables.
+///
+/// This works like [`use_world`], but every `use_world_shared` with the same parameter types
+/// shares a single [`SystemState`], so its archetypes are only updated once per frame.
+/// Deferred parameters (like [`Commands`]) are still applied after each call to `with_world`.
+///
+/// Shared state includes change ticks, [`Local`]s, and event cursors,
+/// so this is best suited to plain queries and resources.
+/// Prefer [`use_world`] for parameters like [`EventReader`] or filters like [`Changed`],
+/// which would otherwise only be observed by the first composable to run each frame.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Labels;
+///
+/// impl Compose for Labels {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let count = use_mut(&cx, || 0);
+///
+///         use_world_shared(&cx, move |query: Query<&Text>| {
+///             SignalMut::set_if_neq(count, query.iter().count())
+///         });
+///
+///         spawn(Text::new(format!("Labels: {}", *count)))
+///     }
+/// }
+/// ```
+pub fn use_world_shared<'a, Marker, F>(cx: ScopeState<'a>, mut with_world: F)
+where
+    F: SystemParamFunction<Marker, In = (), Out = ()> + 'a,
+{
+    let system_state = use_shared_system_state::<F::Param>(cx);
+
+    use_world_listener(cx, move |world| {
+        let mut system_state = system_state.borrow_mut();
+
+        let params = system_state.get_mut(world);
+        with_world.run((), params);
+
+        system_state.apply(world);
+    });
+}
+
+/// Use the [`SystemState`] for `P` from the shared pool, creating it if needed.
+///
+/// The state is removed from the pool once its last user is dropped.
+fn use_shared_system_state<'a, P: SystemParam + 'static>(
+    cx: ScopeState<'a>,
+) -> &'a Rc<RefCell<SystemState<P>>> {
+    let system_state = use_ref(cx, || {
+        let rt_cx = RuntimeContext::current();
+        let mut inner = rt_cx.inner.borrow_mut();
+
+        if let Some(system_state) = inner.system_states.get(&TypeId::of::<P>()) {
+            return system_state
+                .clone()
+                .downcast::<RefCell<SystemState<P>>>()
+                .unwrap();
+        }
+
+        // Safety: The world pointer is valid during composition.
+        let world = unsafe { &mut *inner.world_ptr };
+        let system_state = Rc::new(RefCell::new(SystemState::<P>::new(world)));
+        inner
+            .system_states
+            .insert(TypeId::of::<P>(), system_state.clone());
+        system_state
+    });
+
+    use_drop(cx, move || {
+        // Only the pool and this scope hold the state, so remove it from the pool.
+        if Rc::strong_count(system_state) == 2 {
+            RuntimeContext::current()
+                .inner
+                .borrow_mut()
+                .system_states
+                .remove(&TypeId::of::<P>());
+        }
+    });
+
+    system_state
+}
+
+/// Use a value returned from one or more [`SystemParam`]s from the ECS world.
+///
+/// `with_world` will be called immediately to get the initial value,
+/// then on every frame with the latest query.
+/// The returned [`Signal`] will only re-compose this scope when the returned value has changed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// // Timer composable.
+/// #[derive(Data)]
+/// struct Timer;
+///
+/// impl Compose for Timer {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let elapsed = use_world_value(&cx, |time: Res<Time>| time.elapsed_secs() as u32);
+///
+///         spawn(Text::new(format!("Elapsed: {}s", *elapsed)))
+///     }
+/// }
+/// ```
+pub fn use_world_value<'a, Marker, F>(cx: ScopeState<'a>, mut with_world: F) -> Signal<'a, F::Out>
+where
+    F: SystemParamFunction<Marker, In = ()> + 'a,
+    F::Out: Clone + PartialEq + Send + 'static,
+{
+    let system_state_cell: &RefCell<Option<SystemState<F::Param>>> =
+        use_ref(cx, || RefCell::new(None));
+
+    let value = use_mut(cx, || {
+        let world = unsafe { RuntimeContext::current().world_mut() };
+
+        let mut system_state_cell = system_state_cell.borrow_mut();
+        let system_state = system_state_cell.get_or_insert_with(|| SystemState::new(world));
+
+        let out = with_world.run((), system_state.get_mut(world));
+        system_state.apply(world);
+        out
+    });
+
+    use_world_listener(cx, move |world| {
+        let mut system_state_cell = system_state_cell.borrow_mut();
+        let system_state = system_state_cell.get_or_insert_with(|| SystemState::new(world));
+
+        let out = with_world.run((), system_state.get_mut(world));
+        system_state.apply(world);
+
+        SignalMut::set_if_neq(value, out);
+    });
+
+    SignalMut::as_ref(value)
+}
+
+/// Use the value of a component of type `T` on `entity`.
+///
+/// The component is read immediately, then on every frame.
+/// This scope is re-composed when the component is inserted, removed, or changed.
+/// Returns `None` until the component exists (e.g. before UI layout adds a `ComputedNode` to a spawned node),
+/// which enables measure-then-layout patterns.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::{prelude::*, ui::ComputedNode};
+///
+/// #[derive(Data)]
+/// struct Measured;
+///
+/// impl Compose for Measured {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let entity = use_mut(&cx, || Entity::PLACEHOLDER);
+///         let node = use_entity_component::<ComputedNode>(&cx, *entity);
+///
+///         let label = match node {
+///             Some(node) => format!("{} px wide", node.size().x),
+///             None => String::from("Measuring..."),
+///         };
+///
+///         spawn(Text::new(label)).on_spawn(move |entity_mut| {
+///             SignalMut::set(entity, entity_mut.id());
+///         })
+///     }
+/// }
+/// ```
+pub fn use_entity_component<T>(cx: ScopeState<'_>, entity: Entity) -> Option<Signal<'_, T>>
+where
+    T: Component + Clone + PartialEq,
+{
+    // Whether the component exists, along with its last value.
+    // The last value is kept after the component is removed, so returned signals are always valid.
+    let state = use_mut(cx, || {
+        let world = unsafe { RuntimeContext::current().world_mut() };
+        let value = world.get::<T>(entity).cloned();
+        (value.is_some(), value)
+    });
+
+    use_world_listener(cx, move |world| {
+        let value = world.get::<T>(entity);
+
+        let is_changed = match value {
+            Some(value) => !state.0 || state.1.as_ref() != Some(value),
+            None => state.0,
+        };
+        if !is_changed {
+            return;
+        }
+
+        let value = value.cloned();
+        SignalMut::update(state, move |state| {
+            state.0 = value.is_some();
+            if value.is_some() {
+                state.1 = value;
+            }
+        });
+    });
+
+    if !state.0 {
+        return None;
+    }
+
+    let state = SignalMut::as_ref(state);
+    Some(Signal {
+        value: state.value.1.as_ref().unwrap(),
+        generation: state.generation,
     })
-    .clone();
+}
+
+/// Use a function that is called with the ECS world on every frame.
+fn use_world_listener<'a>(cx: ScopeState<'a>, f: impl FnMut(&'static mut World) + 'a) {
+    let f: Rc<dyn Fn(&'static mut World)> = use_callback(cx, f).clone();
 
     let key = *use_ref(cx, || {
         let f: Rc<dyn Fn(&mut World)> = unsafe { mem::transmute(f) };
@@ -454,7 +841,7 @@ impl_trait_for_tuples!(impl_system_param_fn_once);
 /// Use one or more [`SystemParam`]s from the ECS world.
 ///
 /// `with_world` will be called once during the first composition.
-pub fn use_world_once<Marker, F>(cx: ScopeState, with_world: F) -> &F::Output
+pub fn use_world_once<Marker, F>(cx: ScopeState<'_>, with_world: F) -> &F::Output
 where
     F: SystemParamFunctionOnce<Marker>,
 {
@@ -469,6 +856,175 @@ where
     })
 }
 
+/// Use a [`Resource`] from the ECS world.
+///
+/// The returned [`Signal`] is updated every frame, re-composing this scope only when the resource has changed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Resource, Clone, PartialEq)]
+/// struct Score(i32);
+///
+/// // Score composable.
+/// #[derive(Data)]
+/// struct ScoreText;
+///
+/// impl Compose for ScoreText {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let score = use_resource::<Score>(&cx);
+///
+///         spawn(Text::new(format!("Score: {}", score.0)))
+///     }
+/// }
+/// ```
+pub fn use_resource<R>(cx: ScopeState<'_>) -> Signal<'_, R>
+where
+    R: Resource + Clone + PartialEq,
+{
+    let value = use_mut(cx, || {
+        let world = unsafe { RuntimeContext::current().world_mut() };
+        world.resource::<R>().clone()
+    });
+
+    use_world(cx, move |resource: Res<R>| {
+        if resource.is_changed() {
+            SignalMut::set_if_neq(value, resource.clone());
+        }
+    });
+
+    SignalMut::as_ref(value)
+}
+
+/// Mutable handle to a [`Resource`] in the ECS world.
+///
+/// For more see [`use_resource_mut`].
+pub struct ResourceMut<'a, R> {
+    _marker: PhantomData<fn() -> &'a R>,
+}
+
+impl<R: Resource> ResourceMut<'_, R> {
+    /// Queue an update to this resource.
+    ///
+    /// Updates are applied to the world in order at the start of the next composition, before any content is composed.
+    /// Composables reading this resource (e.g. with [`use_resource`]) will be re-composed if it changes.
+    pub fn update(self, f: impl FnOnce(&mut R) + 'static) {
+        let mut f_cell = Some(f);
+
+        RuntimeContext::current()
+            .inner
+            .borrow_mut()
+            .updates
+            .push(Box::new(move |world| {
+                if let Some(f) = f_cell.take() {
+                    f(&mut world.resource_mut::<R>())
+                }
+            }));
+    }
+
+    /// Queue an update to set the value of this resource.
+    pub fn set(self, value: R) {
+        self.update(move |resource| *resource = value)
+    }
+}
+
+impl<R> Clone for ResourceMut<'_, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for ResourceMut<'_, R> {}
+
+/// Use a mutable handle to a [`Resource`] from the ECS world.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Resource, Clone, PartialEq)]
+/// struct Score(i32);
+///
+/// // Score button composable.
+/// #[derive(Data)]
+/// struct ScoreButton;
+///
+/// impl Compose for ScoreButton {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let score = use_resource::<Score>(&cx);
+///         let score_mut = use_resource_mut::<Score>(&cx);
+///
+///         spawn(Text::new(format!("Score: {}", score.0)))
+///             .observe(move |_: Trigger<Pointer<Click>>| score_mut.update(|score| score.0 += 1))
+///     }
+/// }
+/// ```
+pub fn use_resource_mut<R: Resource>(_cx: ScopeState<'_>) -> ResourceMut<'_, R> {
+    ResourceMut {
+        _marker: PhantomData,
+    }
+}
+
+/// Use the events of type `E` sent since the last composition of this scope.
+///
+/// Events are read from the ECS world every frame and buffered in this scope,
+/// re-composing this scope when new events arrive.
+/// Unlike reading an [`EventReader`] in [`use_world`], no events are missed between compositions.
+/// Events returned from this hook are cleared on the next composition.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Scored(i32);
+///
+/// // Score log composable.
+/// #[derive(Data)]
+/// struct ScoreLog;
+///
+/// impl Compose for ScoreLog {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         for Scored(points) in use_events::<Scored>(&cx) {
+///             dbg!(points);
+///         }
+///     }
+/// }
+/// ```
+pub fn use_events<E>(cx: ScopeState<'_>) -> &[E]
+where
+    E: Event + Clone,
+{
+    let buffer = use_ref(cx, || RefCell::new(Vec::new()));
+    let updater = use_mut(cx, || ());
+
+    use_world(cx, move |mut reader: EventReader<E>| {
+        let mut buffer = buffer.borrow_mut();
+        let len = buffer.len();
+
+        buffer.extend(reader.read().cloned());
+
+        if buffer.len() > len {
+            SignalMut::set(updater, ());
+        }
+    });
+
+    let events = use_ref(cx, || UnsafeCell::new(Vec::new()));
+
+    // Safety: `events` is only mutated here, before being borrowed for this composition.
+    let events = unsafe { &mut *events.get() };
+    *events = mem::take(&mut *buffer.borrow_mut());
+
+    events
+}
+
 /// Hook for [`use_commands`].
 pub struct UseCommands {
     commands: Rc<RefCell<CommandQueue>>,
@@ -485,7 +1041,7 @@ impl UseCommands {
 }
 
 /// Use access to the current [`Command`] queue.
-pub fn use_commands(cx: ScopeState) -> &UseCommands {
+pub fn use_commands(cx: ScopeState<'_>) -> &UseCommands {
     use_ref(cx, || {
         let commands = RuntimeContext::current().inner.borrow().commands.clone();
         UseCommands { commands }
@@ -494,13 +1050,14 @@ pub fn use_commands(cx: ScopeState) -> &UseCommands {
 
 struct SpawnContext {
     parent_entity: Entity,
+    child_offset: usize,
     keys: RefCell<BTreeSet<Pending>>,
 }
 
 /// Use a spawned bundle.
 ///
 /// `make_bundle` is called once to create the bundle.
-pub fn use_bundle<B: Bundle>(cx: ScopeState, make_bundle: impl FnOnce() -> B) -> Entity {
+pub fn use_bundle<B: Bundle>(cx: ScopeState<'_>, make_bundle: impl FnOnce() -> B) -> Entity {
     use_bundle_inner(cx, |world, cell| {
         let bundle = make_bundle();
         if let Some(entity) = cell {
@@ -511,7 +1068,10 @@ pub fn use_bundle<B: Bundle>(cx: ScopeState, make_bundle: impl FnOnce() -> B) ->
     })
 }
 
-fn use_bundle_inner(cx: ScopeState, spawn: impl FnOnce(&mut World, &mut Option<Entity>)) -> Entity {
+fn use_bundle_inner(
+    cx: ScopeState<'_>,
+    spawn: impl FnOnce(&mut World, &mut Option<Entity>),
+) -> Entity {
     let mut f_cell = Some(spawn);
     let entity = *use_ref(cx, || {
         let world = unsafe { RuntimeContext::current().world_mut() };
@@ -527,8 +1087,10 @@ fn use_bundle_inner(cx: ScopeState, spawn: impl FnOnce(&mut World, &mut Option<E
     }
 
     use_drop(cx, move || {
-        let world = unsafe { RuntimeContext::current().world_mut() };
-        world.try_despawn(entity);
+        // The entity is despawned with the world if it's being dropped.
+        if let Some(world) = unsafe { RuntimeContext::current().try_world_mut() } {
+            world.try_despawn(entity);
+        }
     });
 
     entity
@@ -659,6 +1221,73 @@ pub trait Modify<'a> {
         })
     }
 
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Hide this composable's spawned [`Node`] if `cond` is `false`.
+    ///
+    /// Hidden content stays mounted: its state (and entities) are kept,
+    /// and it is only removed from layout with [`Display::None`].
+    /// Visible content is displayed with [`Display::Flex`].
+    ///
+    /// To drop the content's state instead, see [`Modify::mount_if`].
+    fn display_if(self, cond: bool) -> Self
+    where
+        Self: Sized,
+    {
+        self.display(if cond { Display::Flex } else { Display::None })
+    }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Set the [`ZIndex`] of this composable's spawned [`Node`].
+    ///
+    /// Nodes with a higher z-index are rendered above their siblings, regardless of the order they were spawned in.
+    /// The z-index is updated whenever this composable is re-composed.
+    /// To render above nodes outside of this node's siblings (e.g. for a tooltip), use [`GlobalZIndex`] instead.
+    fn z_index(self, z_index: i32) -> Self
+    where
+        Self: Sized,
+    {
+        self.on_insert(move |mut entity| {
+            entity.insert(ZIndex(z_index));
+        })
+    }
+
+    /// Mount this composable only if `cond` is `true`.
+    ///
+    /// Unmounted content is dropped, along with its state and spawned entities,
+    /// and will be mounted with fresh state when `cond` is `true` again.
+    ///
+    /// To keep the content's state while hidden, see [`Modify::display_if`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct Menu;
+    ///
+    /// impl Compose for Menu {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let is_open = use_mut(&cx, || false);
+    ///
+    ///         (
+    ///             button(text::label("Toggle"))
+    ///                 .on_click(move || SignalMut::update(is_open, |x| *x = !*x)),
+    ///             container(text::label("Menu")).mount_if(*is_open),
+    ///         )
+    ///     }
+    /// }
+    /// ```
+    fn mount_if(self, cond: bool) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        cond.then_some(self)
+    }
+
     ui_methods!(
         display: Display,
         position_type: PositionType,
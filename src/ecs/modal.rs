@@ -0,0 +1,92 @@
+use super::use_world;
+use crate::{use_callback0, use_context, use_drop, use_ref, ScopeState};
+use bevy_ecs::system::Res;
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use std::cell::{Cell, RefCell};
+
+/// Modal context for [`use_escape`].
+///
+/// This holds a stack of open modals, where only the topmost modal responds to the escape key.
+/// This is provided by [`material_ui`](crate::ui::material::material_ui),
+/// or can be provided manually with [`use_provider`](crate::use_provider).
+#[derive(Default)]
+pub struct ModalContext {
+    stack: RefCell<Vec<u64>>,
+    next_id: Cell<u64>,
+}
+
+impl ModalContext {
+    /// Returns the number of open modals.
+    pub fn len(&self) -> usize {
+        self.stack.borrow().len()
+    }
+
+    /// Returns `true` if there are no open modals.
+    pub fn is_empty(&self) -> bool {
+        self.stack.borrow().is_empty()
+    }
+
+    fn push(&self) -> u64 {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+
+        self.stack.borrow_mut().push(id);
+        id
+    }
+
+    fn remove(&self, id: u64) {
+        self.stack.borrow_mut().retain(|&x| x != id);
+    }
+
+    fn is_top(&self, id: u64) -> bool {
+        self.stack.borrow().last() == Some(&id)
+    }
+}
+
+/// Use a handler that calls `on_dismiss` when the escape key is pressed while this is the topmost modal.
+///
+/// Each scope using this hook is pushed onto the [`ModalContext`] stack when it's first composed,
+/// and removed when it's dropped.
+/// Nested modals are opened after their parent, so they're dismissed first (in LIFO order).
+///
+/// # Panics
+/// Panics if a [`ModalContext`] was not provided by a parent composable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Dialog<'a> {
+///     is_open: SignalMut<'a, bool>,
+/// }
+///
+/// impl Compose for Dialog<'_> {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_open = cx.me().is_open;
+///         use_escape(&cx, move || SignalMut::set(is_open, false));
+///
+///         container(text::label("Press escape to close"))
+///     }
+/// }
+/// ```
+pub fn use_escape<'a>(cx: ScopeState<'a>, on_dismiss: impl Fn() + 'a) {
+    let modal_cx = use_context::<ModalContext>(cx)
+        .expect("`use_escape` requires a `ModalContext`.")
+        .clone();
+
+    let on_dismiss = use_callback0(cx, on_dismiss).clone();
+
+    let id = *use_ref(cx, || modal_cx.push());
+
+    let drop_cx = modal_cx.clone();
+    use_drop(cx, move || drop_cx.remove(id));
+
+    use_world(cx, move |input: Res<ButtonInput<KeyCode>>| {
+        if input.just_pressed(KeyCode::Escape) && modal_cx.is_top(id) {
+            on_dismiss();
+        }
+    });
+}
@@ -0,0 +1,298 @@
+use super::{use_world, use_world_value};
+use crate::{
+    compose::Compose, data::Data, use_callback0, use_context, use_drop, use_provider, use_ref,
+    Scope, ScopeState, Signal,
+};
+use bevy_ecs::system::Res;
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use slotmap::{DefaultKey, SlotMap};
+use std::{cell::RefCell, mem, rc::Rc};
+
+/// Key combination for a keyboard shortcut.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// // Ctrl + Shift + S
+/// let combo = KeyCombo::new(KeyCode::KeyS).ctrl().shift();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    key: KeyCode,
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+    super_key: bool,
+}
+
+impl KeyCombo {
+    /// Create a new key combination for `key`, with no modifiers.
+    pub fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+            super_key: false,
+        }
+    }
+
+    /// Require the control key to be held.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Require the shift key to be held.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Require the alt key to be held.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Require the super (e.g. command or windows) key to be held.
+    pub fn super_key(mut self) -> Self {
+        self.super_key = true;
+        self
+    }
+
+    /// Get the number of modifiers in this key combination.
+    fn specificity(&self) -> usize {
+        [self.ctrl, self.shift, self.alt, self.super_key]
+            .into_iter()
+            .filter(|x| *x)
+            .count()
+    }
+
+    /// Returns `true` if this key combination was just pressed.
+    fn is_just_pressed(&self, input: &ButtonInput<KeyCode>) -> bool {
+        let is_held = |left, right| input.any_pressed([left, right]);
+
+        input.just_pressed(self.key)
+            && (!self.ctrl || is_held(KeyCode::ControlLeft, KeyCode::ControlRight))
+            && (!self.shift || is_held(KeyCode::ShiftLeft, KeyCode::ShiftRight))
+            && (!self.alt || is_held(KeyCode::AltLeft, KeyCode::AltRight))
+            && (!self.super_key || is_held(KeyCode::SuperLeft, KeyCode::SuperRight))
+    }
+
+    /// Returns `true` if this key combination was just pressed, with exactly its modifiers held.
+    fn is_just_pressed_exact(&self, input: &ButtonInput<KeyCode>) -> bool {
+        let is_held = |left, right| input.any_pressed([left, right]);
+
+        input.just_pressed(self.key)
+            && self.ctrl == is_held(KeyCode::ControlLeft, KeyCode::ControlRight)
+            && self.shift == is_held(KeyCode::ShiftLeft, KeyCode::ShiftRight)
+            && self.alt == is_held(KeyCode::AltLeft, KeyCode::AltRight)
+            && self.super_key == is_held(KeyCode::SuperLeft, KeyCode::SuperRight)
+    }
+}
+
+impl From<KeyCode> for KeyCombo {
+    fn from(key: KeyCode) -> Self {
+        Self::new(key)
+    }
+}
+
+type Handler = (KeyCombo, Rc<dyn Fn()>);
+
+/// Shortcut context for [`use_shortcut`].
+///
+/// This is provided by [`shortcuts`].
+#[derive(Default)]
+pub struct ShortcutContext {
+    handlers: RefCell<SlotMap<DefaultKey, Handler>>,
+}
+
+impl ShortcutContext {
+    /// Run the handlers of any key combinations that were just pressed.
+    ///
+    /// For each pressed key, only the most specific matching combination (with the most modifiers) is run.
+    fn dispatch(&self, input: &ButtonInput<KeyCode>) {
+        let mut matches: Vec<Handler> = Vec::new();
+
+        for (combo, handler) in self.handlers.borrow().values() {
+            if !combo.is_just_pressed(input) {
+                continue;
+            }
+
+            if let Some(last) = matches.iter_mut().find(|(last, _)| last.key == combo.key) {
+                if combo.specificity() > last.0.specificity() {
+                    *last = (*combo, handler.clone());
+                }
+            } else {
+                matches.push((*combo, handler.clone()));
+            }
+        }
+
+        // Handlers are run after releasing the registry, so they can register new shortcuts.
+        for (_, handler) in matches {
+            handler();
+        }
+    }
+}
+
+/// Create a composable that provides a [`ShortcutContext`] to its content.
+///
+/// Keyboard input is read once per frame for all shortcuts registered with [`use_shortcut`].
+pub fn shortcuts<C: Compose>(content: C) -> Shortcuts<C> {
+    Shortcuts { content }
+}
+
+/// Composable that provides a [`ShortcutContext`] to its content.
+///
+/// For more see [`shortcuts`].
+#[derive(Data)]
+#[actuate(path = "crate")]
+pub struct Shortcuts<C> {
+    content: C,
+}
+
+impl<C: Compose> Compose for Shortcuts<C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let shortcut_cx = use_provider(&cx, ShortcutContext::default);
+
+        use_world(&cx, move |input: Res<ButtonInput<KeyCode>>| {
+            shortcut_cx.dispatch(&input);
+        });
+
+        // Safety: The content of this composable is borrowed from `cx.me()`.
+        unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
+    }
+}
+
+/// Use a keyboard shortcut that calls `f` when `combo` is pressed.
+///
+/// Shortcuts are dispatched regardless of focus, and the most specific shortcut
+/// (with the most modifiers) is called when multiple shortcuts match the same key.
+/// The shortcut is removed when this scope is dropped.
+///
+/// # Panics
+/// Panics if a [`ShortcutContext`] was not provided by a parent [`shortcuts`] composable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Editor;
+///
+/// impl Compose for Editor {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         use_shortcut(&cx, KeyCombo::new(KeyCode::KeyS).ctrl(), || {
+///             dbg!("Saved!");
+///         });
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         shortcuts(Editor)
+///     }
+/// }
+/// ```
+pub fn use_shortcut<'a>(cx: ScopeState<'a>, combo: impl Into<KeyCombo>, f: impl Fn() + 'a) {
+    let shortcut_cx = use_context::<ShortcutContext>(cx)
+        .expect("`use_shortcut` must be used inside of a `shortcuts` composable.");
+
+    let f = use_callback0(cx, f);
+    let combo = combo.into();
+
+    let key = *use_ref(cx, || {
+        // Safety: The handler is removed from the context when this scope is dropped.
+        let f: Rc<dyn Fn()> = unsafe { mem::transmute(f.clone()) };
+
+        shortcut_cx.handlers.borrow_mut().insert((combo, f))
+    });
+
+    // Update the key combination in case it was changed.
+    if let Some((last, _)) = shortcut_cx.handlers.borrow_mut().get_mut(key) {
+        *last = combo;
+    }
+
+    use_drop(cx, move || {
+        shortcut_cx.handlers.borrow_mut().remove(key);
+    });
+}
+
+/// Use the action of the key binding triggered on this frame.
+///
+/// A binding is triggered when its key was just pressed with exactly its modifiers held,
+/// so `Ctrl + S` won't also trigger a binding for `S`.
+/// At most one action is returned per frame: if several bindings are triggered,
+/// the most specific one (with the most modifiers) wins, followed by the first in `bindings`.
+///
+/// Keyboard input is read with a single listener for all bindings,
+/// and this scope is re-composed when an action is triggered (and again on the next frame to reset it).
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Action {
+///     Save,
+///     SaveAs,
+/// }
+///
+/// #[derive(Data)]
+/// struct Editor;
+///
+/// impl Compose for Editor {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let action = use_key_bindings(
+///             &cx,
+///             &[
+///                 (KeyCombo::new(KeyCode::KeyS).ctrl(), Action::Save),
+///                 (KeyCombo::new(KeyCode::KeyS).ctrl().shift(), Action::SaveAs),
+///             ],
+///         );
+///
+///         match action {
+///             Some(Action::Save) => {
+///                 dbg!("Saved!");
+///             }
+///             Some(Action::SaveAs) => {
+///                 dbg!("Saved as!");
+///             }
+///             None => {}
+///         }
+///     }
+/// }
+/// ```
+pub fn use_key_bindings<A>(cx: ScopeState<'_>, bindings: &[(KeyCombo, A)]) -> Option<A>
+where
+    A: Clone + PartialEq + Send + 'static,
+{
+    let bindings = bindings.to_vec();
+
+    let action = use_world_value(cx, move |input: Res<ButtonInput<KeyCode>>| {
+        let mut triggered: Option<&(KeyCombo, A)> = None;
+
+        for binding in &bindings {
+            if binding.0.is_just_pressed_exact(&input)
+                && triggered.is_none_or(|last| binding.0.specificity() > last.0.specificity())
+            {
+                triggered = Some(binding);
+            }
+        }
+
+        triggered.map(|(_, action)| action.clone())
+    });
+
+    (*action).clone()
+}
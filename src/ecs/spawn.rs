@@ -1,10 +1,12 @@
 use super::{use_bundle_inner, RuntimeContext, SpawnContext, SystemParamFunction};
 use crate::{
-    compose::Compose, composer::Runtime, data::Data, use_context, use_drop, use_provider, use_ref,
-    Scope, Signal,
+    compose::{Compose, IntoCompose},
+    composer::Runtime,
+    data::Data,
+    use_cell, use_context, use_drop, use_drop_ordered, use_provider, use_ref, Scope, Signal,
 };
 use bevy_ecs::{entity::Entity, prelude::*, world::World};
-use bevy_hierarchy::BuildChildren;
+use bevy_hierarchy::{BuildChildren, Children};
 use std::{
     cell::{Cell, RefCell},
     collections::BTreeSet,
@@ -54,6 +56,7 @@ where
         observer_guard: Arc::new(Mutex::new(true)),
         on_spawn: Vec::new(),
         on_insert: Vec::new(),
+        on_despawn: Vec::new(),
     }
 }
 
@@ -75,6 +78,7 @@ pub struct Spawn<'a, C = ()> {
     observer_fns: Vec<ObserverFn<'a>>,
     on_spawn: Vec<OnInsertFn<'a>>,
     on_insert: Vec<OnInsertFn<'a>>,
+    on_despawn: Vec<OnInsertFn<'a>>,
     observer_guard: Arc<Mutex<bool>>,
 }
 
@@ -88,21 +92,22 @@ impl<'a, C> Spawn<'a, C> {
     }
 
     /// Set the child content.
-    pub fn content<C2>(self, content: C2) -> Spawn<'a, C2> {
+    pub fn content<C2: IntoCompose>(self, content: C2) -> Spawn<'a, C2::Output> {
         Spawn {
             spawn_fn: self.spawn_fn,
-            content,
+            content: content.into_compose(),
             target: self.target,
             observer_fns: self.observer_fns,
             observer_guard: Arc::new(Mutex::new(false)),
             on_spawn: self.on_spawn,
             on_insert: self.on_insert,
+            on_despawn: self.on_despawn,
         }
     }
 
     /// Add a function to be called when this bundle is initially spawned.
     pub fn on_spawn(mut self, f: impl Fn(EntityWorldMut) + 'a) -> Self {
-        self.on_insert.push(Rc::new(f));
+        self.on_spawn.push(Rc::new(f));
         self
     }
 
@@ -112,6 +117,16 @@ impl<'a, C> Spawn<'a, C> {
         self
     }
 
+    /// Add a function to be called just before the spawned entity is despawned.
+    ///
+    /// This is called when this composable is dropped, while the entity still exists in the world,
+    /// so its final component state can be read (or other entities notified).
+    /// This isn't called if the entity was already despawned externally.
+    pub fn on_despawn(mut self, f: impl Fn(EntityWorldMut) + 'a) -> Self {
+        self.on_despawn.push(Rc::new(f));
+        self
+    }
+
     /// Add an observer to the spawned entity.
     pub fn observe<F, E, B, Marker>(mut self, observer: F) -> Self
     where
@@ -163,7 +178,7 @@ impl<C: Compose> Compose for Spawn<'_, C> {
 
         let spawn_cx = use_context::<SpawnContext>(&cx);
 
-        let is_initial = use_ref(&cx, || Cell::new(true));
+        let is_initial = use_cell(&cx, || true);
         let entity = use_bundle_inner(&cx, |world, entity| {
             if let Some(target) = cx.me().target {
                 *entity = Some(target);
@@ -197,6 +212,27 @@ impl<C: Compose> Compose for Spawn<'_, C> {
         });
         let key = use_ref(&cx, || rt.pending(rt.current_key.get()));
 
+        // Use the latest despawn functions.
+        let on_despawn: &RefCell<Vec<OnInsertFn<'static>>> = use_ref(&cx, RefCell::default);
+        // Safety: The despawn functions are replaced on every composition, and only called while this scope is alive.
+        *on_despawn.borrow_mut() = unsafe {
+            mem::transmute::<Vec<OnInsertFn>, Vec<OnInsertFn<'static>>>(cx.me().on_despawn.clone())
+        };
+
+        // Run despawn functions before the entity is despawned by `use_bundle_inner`.
+        use_drop_ordered(&cx, 1, move || {
+            let Some(world) = (unsafe { RuntimeContext::current().try_world_mut() }) else {
+                return;
+            };
+            if world.get_entity(entity).is_err() {
+                return;
+            }
+
+            for f in on_despawn.take() {
+                f(world.entity_mut(entity));
+            }
+        });
+
         use_provider(&cx, || {
             if cx.me().target.is_none() {
                 if let Ok(spawn_cx) = spawn_cx {
@@ -209,6 +245,14 @@ impl<C: Compose> Compose for Spawn<'_, C> {
                         .position(|pending| pending.key == rt.current_key.get())
                     {
                         let world = unsafe { RuntimeContext::current().world_mut() };
+
+                        // Clamp the index in case the parent's children were changed externally.
+                        let len = world
+                            .get::<Children>(spawn_cx.parent_entity)
+                            .map(|children| children.len())
+                            .unwrap_or_default();
+                        let idx = (spawn_cx.child_offset + idx).min(len);
+
                         world
                             .entity_mut(spawn_cx.parent_entity)
                             .insert_children(idx, &[entity]);
@@ -218,6 +262,7 @@ impl<C: Compose> Compose for Spawn<'_, C> {
 
             SpawnContext {
                 parent_entity: entity,
+                child_offset: 0,
                 keys: RefCell::new(BTreeSet::new()),
             }
         });
@@ -0,0 +1,73 @@
+use super::use_world_value;
+use crate::{ScopeState, Signal};
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_window::{PrimaryWindow, Window};
+
+/// Use the logical size of the primary window.
+///
+/// The size is rounded to whole logical pixels,
+/// so this scope is only re-composed when the window is resized by at least one pixel.
+/// If there is no primary window (e.g. in a headless app), this returns [`Vec2::ZERO`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Layout;
+///
+/// impl Compose for Layout {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let size = use_window_size(&cx);
+///
+///         spawn(Text::new(format!("{} x {}", size.x, size.y)))
+///     }
+/// }
+/// ```
+pub fn use_window_size(cx: ScopeState<'_>) -> Signal<'_, Vec2> {
+    use_world_value(cx, |windows: Query<&Window, With<PrimaryWindow>>| {
+        windows
+            .get_single()
+            .map(|window| window.size().round())
+            .unwrap_or(Vec2::ZERO)
+    })
+}
+
+/// Use the index of the current breakpoint for the width of the primary window.
+///
+/// `breakpoints` are minimum widths in ascending order.
+/// This returns the number of breakpoints that are less than or equal to the current width,
+/// so a width below the first breakpoint is `0`.
+///
+/// For more see [`use_window_size`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Layout;
+///
+/// impl Compose for Layout {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let breakpoint = use_breakpoint(&cx, &[600., 1200.]);
+///
+///         let label = match breakpoint {
+///             0 => "Compact",
+///             1 => "Medium",
+///             _ => "Expanded",
+///         };
+///         spawn(Text::new(label))
+///     }
+/// }
+/// ```
+pub fn use_breakpoint(cx: ScopeState<'_>, breakpoints: &[f32]) -> usize {
+    let size = use_window_size(cx);
+
+    breakpoints.partition_point(|&breakpoint| breakpoint <= size.x)
+}
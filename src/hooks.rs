@@ -0,0 +1,191 @@
+//! Arena storage for hook values.
+//!
+//! Hook values are bump-allocated into chunks owned by their scope,
+//! instead of allocating a separate `Box` for every hook.
+
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error},
+    vec::Vec,
+};
+use core::{alloc::Layout, any::TypeId, mem, ptr::NonNull};
+
+/// Size of the first chunk allocated by a [`Hooks`] arena.
+const MIN_CHUNK_SIZE: usize = 256;
+
+/// Maximum size of a chunk, values larger than this are allocated separately.
+const MAX_CHUNK_SIZE: usize = 4096;
+
+/// Alignment of each chunk, values with a larger alignment are allocated separately.
+const CHUNK_ALIGN: usize = 16;
+
+/// Chunk of memory for hook values.
+struct Chunk {
+    ptr: NonNull<u8>,
+    size: usize,
+    used: usize,
+}
+
+impl Chunk {
+    fn new(size: usize) -> Self {
+        let layout = Layout::from_size_align(size, CHUNK_ALIGN).unwrap();
+
+        // Safety: `size` is always non-zero.
+        let ptr = unsafe { alloc(layout) };
+        let Some(ptr) = NonNull::new(ptr) else {
+            handle_alloc_error(layout)
+        };
+
+        Self { ptr, size, used: 0 }
+    }
+
+    /// Try to allocate memory for `layout` in this chunk.
+    fn try_alloc(&mut self, layout: Layout) -> Option<NonNull<u8>> {
+        let start = self.used.next_multiple_of(layout.align());
+        let end = start.checked_add(layout.size())?;
+        if end > self.size {
+            return None;
+        }
+
+        self.used = end;
+
+        // Safety: `start` is within the bounds of this chunk.
+        Some(unsafe { self.ptr.add(start) })
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(self.size, CHUNK_ALIGN).unwrap();
+
+        // Safety: This chunk was allocated with the same layout.
+        unsafe { dealloc(self.ptr.as_ptr(), layout) }
+    }
+}
+
+/// Hook value stored in a [`Hooks`] arena.
+struct Entry {
+    ptr: NonNull<u8>,
+    type_id: TypeId,
+    type_name: &'static str,
+    drop_fn: unsafe fn(*mut u8),
+
+    /// Layout of this value if it was allocated outside of a chunk.
+    layout: Option<Layout>,
+}
+
+/// Arena of hook values for a scope.
+///
+/// Values are never moved once pushed, so references to them stay valid until the arena is dropped.
+#[derive(Default)]
+pub(crate) struct Hooks {
+    chunks: Vec<Chunk>,
+    entries: Vec<Entry>,
+}
+
+impl Hooks {
+    /// Get the number of hook values in this arena.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Push a hook value to this arena, returning a pointer to it.
+    pub(crate) fn push<T: 'static>(&mut self, value: T) -> NonNull<T> {
+        unsafe fn drop_value<T>(ptr: *mut u8) {
+            // Safety: `ptr` points to a valid value of type `T`.
+            unsafe { ptr.cast::<T>().drop_in_place() }
+        }
+
+        let layout = Layout::new::<T>();
+        let (ptr, separate_layout) = if layout.size() == 0 {
+            (NonNull::<T>::dangling().cast(), None)
+        } else if layout.align() > CHUNK_ALIGN || layout.size() > MAX_CHUNK_SIZE / 4 {
+            // Safety: `layout` has a non-zero size.
+            let ptr = unsafe { alloc(layout) };
+            let Some(ptr) = NonNull::new(ptr) else {
+                handle_alloc_error(layout)
+            };
+            (ptr, Some(layout))
+        } else {
+            (self.alloc_in_chunk(layout), None)
+        };
+
+        let ptr = ptr.cast::<T>();
+
+        // Safety: `ptr` is valid for writes of `T`.
+        unsafe { ptr.write(value) };
+
+        self.entries.push(Entry {
+            ptr: ptr.cast(),
+            type_id: TypeId::of::<T>(),
+            type_name: core::any::type_name::<T>(),
+            drop_fn: drop_value::<T>,
+            layout: separate_layout,
+        });
+
+        ptr
+    }
+
+    /// Get a pointer to the hook value at `idx`.
+    ///
+    /// # Panics
+    /// Panics if the value at `idx` doesn't exist or isn't of type `T`.
+    pub(crate) fn get<T: 'static>(&self, idx: usize) -> NonNull<T> {
+        self.try_get(idx).unwrap_or_else(|_| {
+            panic!(
+                "Hook type mismatch: hooks must be called in the same order on every composition."
+            )
+        })
+    }
+
+    /// Get a pointer to the hook value at `idx`,
+    /// or the type name of the stored value if it isn't of type `T`.
+    ///
+    /// # Panics
+    /// Panics if the value at `idx` doesn't exist.
+    pub(crate) fn try_get<T: 'static>(&self, idx: usize) -> Result<NonNull<T>, &'static str> {
+        let entry = &self.entries[idx];
+        if entry.type_id != TypeId::of::<T>() {
+            return Err(entry.type_name);
+        }
+
+        Ok(entry.ptr.cast())
+    }
+
+    fn alloc_in_chunk(&mut self, layout: Layout) -> NonNull<u8> {
+        if let Some(ptr) = self
+            .chunks
+            .last_mut()
+            .and_then(|chunk| chunk.try_alloc(layout))
+        {
+            return ptr;
+        }
+
+        let size = self
+            .chunks
+            .last()
+            .map(|chunk| (chunk.size * 2).min(MAX_CHUNK_SIZE))
+            .unwrap_or(MIN_CHUNK_SIZE)
+            // Values can be larger than the next chunk (up to `MAX_CHUNK_SIZE / 4`).
+            .max(layout.size().next_power_of_two());
+
+        let mut chunk = Chunk::new(size);
+        let ptr = chunk.try_alloc(layout).unwrap();
+        self.chunks.push(chunk);
+        ptr
+    }
+}
+
+impl Drop for Hooks {
+    fn drop(&mut self) {
+        // Drop values in the order they were pushed (before their chunks are freed).
+        for entry in mem::take(&mut self.entries) {
+            // Safety: Each entry is a valid value that is only dropped here.
+            unsafe { (entry.drop_fn)(entry.ptr.as_ptr()) };
+
+            if let Some(layout) = entry.layout {
+                // Safety: This value was allocated with the same layout.
+                unsafe { dealloc(entry.ptr.as_ptr(), layout) }
+            }
+        }
+    }
+}
@@ -80,6 +80,7 @@
 //! Hooks must be used in the same order for every re-compose.
 //! Don’t use hooks inside loops, conditions, nested functions, or match blocks.
 //! Instead, always use hooks at the top level of your composable, before any early returns.
+//! For conditional state, use [`use_branch`] to give each branch its own hooks.
 //!
 //! ## Installation
 //! To add this crate to your project:
@@ -89,18 +90,21 @@
 //!
 //! ## Features
 //! - `std`: Enables features that use Rust's standard library (default).
-//!    With this feature disabled Actuate can be used in `#![no_std]` environments.
-//! - `animation`: Enables the `animation` module for animating values from the [Bevy](https://crates.io/crates/bevy) ECS.
+//!   With this feature disabled Actuate can be used in `#![no_std]` environments.
+//! - `animation`: Enables hooks in the `animation` module for animating values from the [Bevy](https://crates.io/crates/bevy) ECS.
 //!   (enables the `ecs` feature).
 //! - `ecs`: Enables the `ecs` module for bindings to the [Bevy](https://crates.io/crates/bevy) ECS.
 //! - `executor`: Enables the `executor` module for multi-threaded tasks.
+//! - `input`: Enables keyboard shortcuts and modal escape handlers in the `ecs` module (enables the `ecs` feature).
 //! - `material`: Enables the `material` module for Material UI (enables the `ecs` and `ui` features).
 //! - `picking`: Enables support for picking event handlers with `Modify` (requires the `ecs` feature).
 //! - `rt` Enables support for the [Tokio](https://crates.io/crates/tokio) runtime with the Executor trait.
 //!   (enables the `executor` feature).
-//! - `tracing`: Enables the logging through the `tracing` crate.
+//! - `tracing`: Enables logging and per-composable spans through the `tracing` crate.
 //! - `ui`: Enables the `ui` module for user interface components.
+//! - `window`: Enables hooks in the `ecs` module for responsive layouts from the primary window (enables the `ecs` feature).
 //! - `full`: Enables all features above.
+//! - `test-util`: Enables the `test_util` module for testing composables.
 
 extern crate alloc;
 
@@ -130,51 +134,140 @@ use std::collections::HashMap;
 /// Prelude of commonly used items.
 pub mod prelude {
     pub use crate::{
-        compose::{self, catch, dyn_compose, memo, Compose, DynCompose, Error, Memo},
-        data::{data, Data},
-        use_callback, use_context, use_drop, use_local_task, use_memo, use_mut, use_provider,
-        use_ref, Cow, Generational, Map, RefMap, Scope, ScopeState, Signal, SignalMut,
+        clone,
+        compose::{
+            self, catch, catch_typed, dyn_compose, memo, retained_result, slotted, suspense,
+            Compose, DynCompose, Error, IntoCompose, Memo, Memoize, RetainedResult, Slotted,
+            Suspense,
+        },
+        data::{data, Data, Props},
+        use_branch, use_callback, use_callback0, use_callback2, use_callback3, use_callback4,
+        use_callback_memo, use_cell, use_changed, use_computed, use_context, use_drop,
+        use_drop_ordered, use_history, use_is_initial, use_local_task, use_memo, use_memo_local,
+        use_memo_quantized, use_model, use_mut, use_post_compose, use_provider, use_provider_memo,
+        use_providers, use_rc, use_recompose, use_ref, use_ref_cell, use_ref_keyed, use_selector,
+        use_stream, use_unmount_notify, Cow, ElementMut, Generational, History, Map, MapMut,
+        Providers, RefMap, Scope, ScopeState, Signal, SignalMut,
+    };
+
+    pub use crate::animation::{use_animated_with_clock, Clock};
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub use crate::{
+        animation::use_mount_time,
+        compose::{catch_unwind, CatchUnwind},
     };
 
     #[cfg(feature = "animation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
-    pub use crate::animation::{use_animated, UseAnimated};
+    pub use crate::animation::{use_animated, use_elapsed, UseAnimated};
 
     #[cfg(feature = "ecs")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ecs")))]
     pub use crate::ecs::{
-        spawn, use_bundle, use_commands, use_world, use_world_once, ActuatePlugin, Composition,
-        Modifier, Modify, Spawn, UseCommands,
+        spawn, use_bundle, use_commands, use_entity_component, use_events, use_resource,
+        use_resource_mut, use_world, use_world_once, use_world_shared, use_world_value,
+        ActuatePlugin, Composition, CompositionError, Modifier, Modify, ResourceMut, Spawn,
+        UseCommands,
     };
 
     #[cfg(feature = "executor")]
     #[cfg_attr(docsrs, doc(cfg(feature = "executor")))]
     pub use crate::use_task;
 
+    #[cfg(feature = "input")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "input")))]
+    pub use crate::ecs::{
+        shortcuts, use_escape, use_key_bindings, use_shortcut, KeyCombo, ModalContext,
+        ShortcutContext, Shortcuts,
+    };
+
+    #[cfg(all(feature = "ecs", feature = "picking"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "ecs", feature = "picking"))))]
+    pub use crate::ecs::{use_drag, DragGesture};
+
+    #[cfg(feature = "window")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "window")))]
+    pub use crate::ecs::{use_breakpoint, use_window_size};
+
+    #[cfg(feature = "rt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+    pub use crate::{use_debounce, use_throttle, use_timeout, Timeout};
+
     #[cfg(feature = "ui")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
-    pub use crate::ui::{scroll_view, ScrollView};
+    pub use crate::ui::{
+        column, lazy, row, scroll_view, tooltip, use_focus, use_focusable, use_hover, Flex,
+        FocusContext, Lazy, ScrollView, Tooltip, VisibilityContext,
+    };
 
     #[cfg(feature = "material")]
     #[cfg_attr(docsrs, doc(cfg(feature = "material")))]
     pub use crate::ui::material::{
-        button, container, material_ui, radio_button, text, Button, MaterialUi, RadioButton, Theme,
-        TypographyKind, TypographyStyleKind,
+        button, container, material_ui, radio_button, radio_group, radio_group_button, text,
+        Button, Density, MaterialUi, RadioButton, RadioGroup, RadioGroupButton, RadioGroupContext,
+        Spacing, Theme, TypographyKind, TypographyStyleKind,
+    };
+
+    #[cfg(all(feature = "material", feature = "animation"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "material", feature = "animation"))))]
+    pub use crate::ui::material::{
+        circular_progress, linear_progress, CircularProgress, LinearProgress,
+    };
+}
+
+/// Clone one or more values into new bindings of the same name.
+///
+/// This is useful for moving clones of owned values (like an [`Rc`]) into closures.
+/// Prefix a name with `mut` to declare a mutable binding.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::rc::Rc;
+///
+/// let name = Rc::new(String::from("Actuate"));
+/// let count = 0;
+///
+/// let f = {
+///     clone!(name, mut count);
+///     move || {
+///         count += 1;
+///         format!("{name}: {count}")
+///     }
+/// };
+///
+/// assert_eq!(Rc::strong_count(&name), 2);
+/// drop(f);
+/// ```
+#[macro_export]
+macro_rules! clone {
+    () => {};
+    (mut $name:ident $(, $($rest:tt)*)?) => {
+        let mut $name = $name.clone();
+        $( $crate::clone!($($rest)*); )?
+    };
+    ($name:ident $(, $($rest:tt)*)?) => {
+        let $name = $name.clone();
+        $( $crate::clone!($($rest)*); )?
     };
 }
 
-#[cfg(feature = "animation")]
-#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
 /// Animation hooks.
 pub mod animation;
 
+mod hooks;
+use self::hooks::Hooks;
+
 /// Composable functions.
 pub mod compose;
 use self::compose::{AnyCompose, Compose};
 
 /// Low-level composer.
 pub mod composer;
-use self::composer::Runtime;
+use self::composer::{Priority, Runtime};
 
 /// Data trait and macros.
 pub mod data;
@@ -190,6 +283,11 @@ pub mod ecs;
 /// Task execution context.
 pub mod executor;
 
+#[cfg(feature = "test-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+/// Utilities for testing composables.
+pub mod test_util;
+
 #[cfg(feature = "ui")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
 /// User interface components.
@@ -228,6 +326,21 @@ impl<T> Cow<'_, T> {
     }
 }
 
+impl<'a, T> Cow<'a, T> {
+    /// Map this value to a value of type `U`.
+    ///
+    /// Borrowed values stay borrowed (see [`RefMap::map`]), while owned values clone the mapped value.
+    pub fn map<U>(me: Self, f: fn(&T) -> &U) -> Cow<'a, U>
+    where
+        U: Clone,
+    {
+        match me {
+            Cow::Borrowed(value) => Cow::Borrowed(RefMap::map(value, f)),
+            Cow::Owned(value) => Cow::Owned(f(&value).clone()),
+        }
+    }
+}
+
 impl<T> Clone for Cow<'_, T>
 where
     T: Clone,
@@ -240,6 +353,12 @@ where
     }
 }
 
+impl<T: Default> Default for Cow<'_, T> {
+    fn default() -> Self {
+        Cow::Owned(T::default())
+    }
+}
+
 impl<T> Deref for Cow<'_, T> {
     type Target = T;
 
@@ -291,6 +410,33 @@ pub enum RefMap<'a, T> {
     Map(Map<'a, T>),
 }
 
+impl<'a, T> RefMap<'a, T> {
+    /// Map this reference to a value of type `U`.
+    ///
+    /// Signals and mapped references are both mapped to a [`Map`] that keeps the generation of their source,
+    /// so the result can still be memoized by generation (see [`Generational`]).
+    /// A mapped reference is projected from its current value, then `f` is called on every dereference.
+    /// References have no generation to track, so they're mapped to another reference.
+    pub fn map<U>(me: Self, f: fn(&T) -> &U) -> RefMap<'a, U> {
+        match me {
+            RefMap::Ref(r) => RefMap::Ref(f(r)),
+            RefMap::Signal(s) => RefMap::Map(Signal::map(s, f)),
+            RefMap::Map(map) => RefMap::Map(Map {
+                ptr: (map.deref_fn)(map.ptr, map.map_fn) as *const T as _,
+                map_fn: f as _,
+                deref_fn: |ptr, g| {
+                    // Safety: `g` is guranteed to be a valid function pointer.
+                    unsafe {
+                        let g: fn(&T) -> &U = mem::transmute(g);
+                        g(&*(ptr as *const T))
+                    }
+                },
+                generation: map.generation,
+            }),
+        }
+    }
+}
+
 impl<T> Clone for RefMap<'_, T> {
     fn clone(&self) -> Self {
         *self
@@ -413,6 +559,29 @@ impl<'a, T> Signal<'a, T> {
         }
     }
 
+    /// Map this reference to an optional value of type `U`, returning `None` if `f` returns `None`.
+    ///
+    /// Like [`Signal::map`], the returned [`Map`] will call `f` on every dereference.
+    ///
+    /// # Panics
+    /// Dereferencing the returned [`Map`] will panic if `f` returns `None` after this function is called.
+    pub fn filter_map<U>(me: Self, f: fn(&T) -> Option<&U>) -> Option<Map<'a, U>> {
+        f(me.value)?;
+
+        Some(Map {
+            ptr: me.value as *const _ as _,
+            map_fn: f as _,
+            deref_fn: |ptr, g| {
+                // Safety: `f` is guranteed to be a valid function pointer.
+                unsafe {
+                    let g: fn(&T) -> Option<&U> = mem::transmute(g);
+                    g(&*(ptr as *const T)).expect("Mapped value is no longer present.")
+                }
+            },
+            generation: me.generation,
+        })
+    }
+
     /// Unsafely map this reference to a value of type `U`.
     /// The returned `MapUnchecked` implements `Compose` to allow for borrowed child composables.
     ///
@@ -466,11 +635,23 @@ pub struct SignalMut<'a, T> {
 impl<'a, T: 'static> SignalMut<'a, T> {
     /// Queue an update to this value, triggering an update to the component owning this value.
     pub fn update(me: Self, f: impl FnOnce(&mut T) + Send + 'static) {
+        Self::update_with_priority(me, Priority::High, f)
+    }
+
+    /// Queue an update to this value with a [`Priority`], triggering an update to the component owning this value.
+    ///
+    /// Components updated with [`Priority::Low`] are composed after any components with a high priority.
+    /// This can be used for background updates (e.g. from a task) that shouldn't delay updates from user input.
+    pub fn update_with_priority(
+        me: Self,
+        priority: Priority,
+        f: impl FnOnce(&mut T) + Send + 'static,
+    ) {
         let scope_key = me.scope_key;
 
         Self::with(me, move |value| {
             let rt = Runtime::current();
-            rt.queue(scope_key);
+            rt.queue_with_priority(scope_key, priority);
 
             f(value)
         })
@@ -516,6 +697,160 @@ impl<'a, T: 'static> SignalMut<'a, T> {
         });
     }
 
+    /// Map this mutable reference to a value of type `U`.
+    ///
+    /// Updates to the returned reference are queued with the same runtime,
+    /// incrementing the generation of this value and triggering an update to the component owning it.
+    /// `f` is called again on each access and update, so the projection always points into the current value.
+    pub fn map_mut<U>(me: Self, f: fn(&mut T) -> &mut U) -> MapMut<'a, U> {
+        MapMut {
+            ptr: me.ptr.as_ptr() as _,
+            map_fn: f as _,
+            project_fn: |ptr, f| {
+                // Safety: `f` is guaranteed to be a valid function pointer of this type.
+                unsafe {
+                    let f: fn(&mut T) -> &mut U = mem::transmute(f);
+                    f(&mut *(ptr as *mut T)) as *mut U
+                }
+            },
+            scope_key: me.scope_key,
+            generation: me.generation,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Split this mutable reference into references to two disjoint values.
+    ///
+    /// Both references are borrowed from the same call to `f`,
+    /// so the borrow checker guarantees they can never alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    ///
+    /// struct Form {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// #[derive(Data)]
+    /// struct App;
+    ///
+    /// impl Compose for App {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let form = use_mut(&cx, || Form {
+    ///             name: String::new(),
+    ///             age: 0,
+    ///         });
+    ///
+    ///         let (name, age) = SignalMut::split2(form, |form| (&mut form.name, &mut form.age));
+    ///         MapMut::set(name, String::from("Matt"));
+    ///         MapMut::set(age, 1);
+    ///     }
+    /// }
+    /// ```
+    pub fn split2<A, B>(
+        me: Self,
+        f: fn(&mut T) -> (&mut A, &mut B),
+    ) -> (MapMut<'a, A>, MapMut<'a, B>) {
+        (
+            MapMut {
+                ptr: me.ptr.as_ptr() as _,
+                map_fn: f as _,
+                project_fn: |ptr, f| {
+                    // Safety: `f` is guaranteed to be a valid function pointer of this type.
+                    unsafe {
+                        let f: fn(&mut T) -> (&mut A, &mut B) = mem::transmute(f);
+                        f(&mut *(ptr as *mut T)).0 as *mut A
+                    }
+                },
+                scope_key: me.scope_key,
+                generation: me.generation,
+                _marker: PhantomData,
+            },
+            MapMut {
+                ptr: me.ptr.as_ptr() as _,
+                map_fn: f as _,
+                project_fn: |ptr, f| {
+                    // Safety: `f` is guaranteed to be a valid function pointer of this type.
+                    unsafe {
+                        let f: fn(&mut T) -> (&mut A, &mut B) = mem::transmute(f);
+                        f(&mut *(ptr as *mut T)).1 as *mut B
+                    }
+                },
+                scope_key: me.scope_key,
+                generation: me.generation,
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /// Get a raw pointer to this value.
+    ///
+    /// The pointer is valid as long as the scope owning this value is alive (for the lifetime `'a`).
+    /// Writing to the pointer bypasses the update queue, so the owning component will not be recomposed
+    /// and this value's generation will not change.
+    pub fn as_ptr(me: Self) -> NonNull<T> {
+        me.ptr
+    }
+
+    /// Decompose this mutable reference into its raw parts.
+    ///
+    /// Returns a pointer to the value, the key of the scope owning the value,
+    /// and a pointer to the generation of the owning scope.
+    /// These can be converted back to a [`SignalMut`] with [`SignalMut::from_raw_parts`].
+    pub fn into_raw_parts(me: Self) -> (NonNull<T>, DefaultKey, *const Cell<u64>) {
+        (me.ptr, me.scope_key, me.generation)
+    }
+
+    /// Create a mutable reference from its raw parts.
+    ///
+    /// This is an escape hatch for bridging state to systems outside of the composition,
+    /// such as immediate-mode APIs or FFI.
+    ///
+    /// # Safety
+    /// The parts must come from [`SignalMut::into_raw_parts`] (or a mapped reference from the same scope), and:
+    /// - `ptr` must point to a value owned by the scope for `scope_key`, and the returned reference
+    ///   must not be used after that scope is dropped (the lifetime `'a` must not outlive it).
+    /// - `generation` must point to the generation of that same scope.
+    ///   The generation is incremented by every update queued through the returned reference,
+    ///   so a dangling or mismatched pointer will cause use-after-free or missed updates.
+    /// - The returned reference must only be used on the thread running the composition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct App;
+    ///
+    /// impl Compose for App {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let count = use_mut(&cx, || 0);
+    ///
+    ///         let (ptr, scope_key, generation) = SignalMut::into_raw_parts(count);
+    ///
+    ///         // Safety: The parts are used while this scope is alive.
+    ///         let count = unsafe { SignalMut::from_raw_parts(ptr, scope_key, generation) };
+    ///         SignalMut::update(count, |x| *x += 1);
+    ///     }
+    /// }
+    /// ```
+    pub unsafe fn from_raw_parts(
+        ptr: NonNull<T>,
+        scope_key: DefaultKey,
+        generation: *const Cell<u64>,
+    ) -> Self {
+        Self {
+            ptr,
+            scope_key,
+            generation,
+            _marker: PhantomData,
+        }
+    }
+
     /// Convert this mutable reference to an immutable reference.
     pub fn as_ref(me: Self) -> Signal<'a, T> {
         Signal {
@@ -533,6 +868,185 @@ impl<T> Deref for SignalMut<'_, T> {
     }
 }
 
+impl<'a, T: 'static> SignalMut<'a, Vec<T>> {
+    /// Get a mutable reference to the element at `idx`, or `None` if it's out of bounds.
+    ///
+    /// Updates to the returned element are queued through this `Vec`,
+    /// incrementing its generation and triggering an update to the component owning it.
+    /// The index is checked again when each update is applied,
+    /// so updates are skipped if the `Vec` was resized past this element in the meantime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct App;
+    ///
+    /// impl Compose for App {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let items = use_mut(&cx, || vec![0, 1, 2]);
+    ///
+    ///         if let Some(item) = SignalMut::index_mut(items, 1) {
+    ///             ElementMut::set_if_neq(item, 5);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn index_mut(me: Self, idx: usize) -> Option<ElementMut<'a, T>> {
+        if idx < me.len() {
+            Some(ElementMut { vec: me, idx })
+        } else {
+            None
+        }
+    }
+}
+
+/// Mutable reference to an element of a `Vec` in a [`SignalMut`].
+///
+/// For more see [`SignalMut::index_mut`].
+pub struct ElementMut<'a, T> {
+    vec: SignalMut<'a, Vec<T>>,
+    idx: usize,
+}
+
+impl<T: 'static> ElementMut<'_, T> {
+    /// Get the index of this element.
+    pub fn index(me: Self) -> usize {
+        me.idx
+    }
+
+    /// Queue an update to this element, triggering an update to the component owning its `Vec`.
+    ///
+    /// If the `Vec` no longer contains this index when the update is applied, this does nothing.
+    pub fn update(me: Self, f: impl FnOnce(&mut T) + Send + 'static) {
+        let idx = me.idx;
+
+        SignalMut::update(me.vec, move |vec| {
+            if let Some(value) = vec.get_mut(idx) {
+                f(value)
+            }
+        })
+    }
+
+    /// Queue an update to this element, triggering an update to the component owning its `Vec`.
+    pub fn set(me: Self, value: T)
+    where
+        T: Send,
+    {
+        ElementMut::update(me, |x| *x = value)
+    }
+
+    /// Queue an update to this element if it is not equal to the given value.
+    pub fn set_if_neq(me: Self, value: T)
+    where
+        T: PartialEq + Send,
+    {
+        if *me != value {
+            ElementMut::set(me, value);
+        }
+    }
+}
+
+impl<T> Clone for ElementMut<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ElementMut<'_, T> {}
+
+impl<T> Deref for ElementMut<'_, T> {
+    type Target = T;
+
+    /// Get the current value of this element.
+    ///
+    /// # Panics
+    /// Panics if the `Vec` no longer contains this index.
+    fn deref(&self) -> &Self::Target {
+        &self.vec[self.idx]
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ElementMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ElementMut")
+            .field("index", &self.idx)
+            .field("value", &self.vec.get(self.idx))
+            .finish()
+    }
+}
+
+unsafe impl<T: Data> Data for ElementMut<'_, T> {}
+
+/// Mapped mutable reference to a value of type `T`.
+///
+/// This stores a pointer to the mapped value's parent, along with the function to map it,
+/// so the mapping is applied again to the current parent value on each access and update.
+///
+/// This can be created with [`SignalMut::map_mut`] or [`SignalMut::split2`].
+pub struct MapMut<'a, T> {
+    ptr: *mut (),
+    map_fn: *const (),
+    project_fn: fn(*mut (), *const ()) -> *mut T,
+    scope_key: DefaultKey,
+    generation: *const Cell<u64>,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<T: 'static> MapMut<'_, T> {
+    /// Queue an update to this value, triggering an update to the component owning its parent.
+    pub fn update(me: Self, f: impl FnOnce(&mut T) + Send + 'static) {
+        let scope_key = me.scope_key;
+        let me = UnsafeWrap((me.ptr, me.map_fn, me.project_fn, me.generation));
+        let cell = UnsafeWrap(Some(f));
+
+        Runtime::current().update(move || {
+            let me = me;
+            let mut cell = cell;
+            let (ptr, map_fn, project_fn, generation) = me.0;
+
+            Runtime::current().queue(scope_key);
+
+            // Safety: Updates are guaranteed to be called before any structural changes of the composition tree,
+            // and the mapping is applied to the current value of the parent.
+            cell.0.take().unwrap()(unsafe { &mut *project_fn(ptr, map_fn) });
+
+            // Safety: the pointer to this scope's generation is guranteed to outlive `me`.
+            let generation = unsafe { &*generation };
+            generation.set(generation.get() + 1)
+        });
+    }
+
+    /// Queue an update to this value, triggering an update to the component owning its parent.
+    pub fn set(me: Self, value: T)
+    where
+        T: Send,
+    {
+        MapMut::update(me, |x| *x = value)
+    }
+
+    /// Queue an update to this value if it is not equal to the given value.
+    pub fn set_if_neq(me: Self, value: T)
+    where
+        T: PartialEq + Send,
+    {
+        if *me != value {
+            MapMut::set(me, value);
+        }
+    }
+}
+
+impl<T> Deref for MapMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: The parent value is pinned in its scope for `'a`.
+        unsafe { &*(self.project_fn)(self.ptr, self.map_fn) }
+    }
+}
+
 macro_rules! impl_pointer {
     ($($t:ident),*) => {
         $(
@@ -583,7 +1097,7 @@ macro_rules! impl_pointer {
         )*
     };
 }
-impl_pointer!(Signal, Map, SignalMut);
+impl_pointer!(Signal, Map, MapMut, SignalMut);
 
 /// Map of [`TypeId`] to context values.
 #[derive(Clone, Default)]
@@ -591,6 +1105,9 @@ struct Contexts {
     values: HashMap<TypeId, Rc<dyn Any>, BuildHasherDefault<AHasher>>,
 }
 
+/// Function that returns `true` if a scope should be re-composed with a new context value.
+type ContextSubscriberFn = Rc<dyn Fn(&Rc<dyn Any>) -> bool>;
+
 /// Scope state of a composable function.
 pub type ScopeState<'a> = &'a ScopeData<'a>;
 
@@ -598,7 +1115,7 @@ pub type ScopeState<'a> = &'a ScopeData<'a>;
 #[derive(Default)]
 pub struct ScopeData<'a> {
     /// Hook values stored in this scope.
-    hooks: UnsafeCell<Vec<Box<dyn Any>>>,
+    hooks: UnsafeCell<Hooks>,
 
     /// Current hook index.
     hook_idx: Cell<usize>,
@@ -609,22 +1126,36 @@ pub struct ScopeData<'a> {
     /// Context values for child composables.
     child_contexts: RefCell<Contexts>,
 
-    /// Drop functions to run just before this scope is dropped.
-    drops: RefCell<Vec<usize>>,
+    /// Functions that check if this scope should be re-composed when a context is re-provided.
+    context_subscribers: RefCell<Vec<(TypeId, ContextSubscriberFn)>>,
+
+    /// Drop functions to run just before this scope is dropped, as `(priority, hook index)`.
+    drops: RefCell<Vec<(i32, usize)>>,
 
     /// Current generation of this scope.
     generation: Cell<u64>,
 
+    /// Index of the composable's own first hook, after any hooks used internally to compose it.
+    own_hook_idx: Cell<usize>,
+
+    /// Tracing span of this scope's last composition.
+    #[cfg(feature = "tracing")]
+    span: RefCell<Option<tracing::Span>>,
+
     /// Marker for the invariant lifetime of this scope.
     _marker: PhantomData<&'a fn(ScopeData<'a>) -> ScopeData<'a>>,
 }
 
 impl Drop for ScopeData<'_> {
     fn drop(&mut self) {
-        for idx in &*self.drops.borrow() {
-            let hooks = unsafe { &mut *self.hooks.get() };
-            let any = hooks.get_mut(*idx).unwrap();
-            (**any).downcast_mut::<Box<dyn FnMut()>>().unwrap()();
+        // Run drop functions from highest to lowest priority (in registration order for equal priorities).
+        let mut drops = self.drops.take();
+        drops.sort_by_key(|(priority, _)| core::cmp::Reverse(*priority));
+
+        for (_, idx) in &drops {
+            let hooks = unsafe { &*self.hooks.get() };
+            let f = hooks.get::<Box<dyn FnMut()>>(*idx);
+            unsafe { (*f.as_ptr())() };
         }
     }
 }
@@ -669,72 +1200,431 @@ impl<'a, C> Deref for Scope<'a, C> {
 /// Use an immutable reference to a value of type `T`.
 ///
 /// `make_value` will only be called once to initialize this value.
-pub fn use_ref<T: 'static>(cx: ScopeState, make_value: impl FnOnce() -> T) -> &T {
+pub fn use_ref<T: 'static>(cx: ScopeState<'_>, make_value: impl FnOnce() -> T) -> &T {
     let hooks = unsafe { &mut *cx.hooks.get() };
 
     let idx = cx.hook_idx.get();
     cx.hook_idx.set(idx + 1);
 
-    let any = if idx >= hooks.len() {
-        hooks.push(Box::new(make_value()));
-        hooks.last().unwrap()
+    let ptr = if idx >= hooks.len() {
+        hooks.push(make_value())
     } else {
-        hooks.get(idx).unwrap()
+        get_hook(cx, hooks, idx)
     };
-    (**any).downcast_ref().unwrap()
+
+    // Safety: Hook values are pinned in their scope's arena.
+    unsafe { ptr.as_ref() }
 }
 
-struct MutState<T> {
-    value: T,
-    generation: Cell<u64>,
+/// Get a pointer to the hook value at `idx` in this scope's arena.
+///
+/// # Panics
+/// Panics with the hook's index and the composable's name if the value at `idx` isn't of type `T`.
+fn get_hook<T: 'static>(cx: ScopeState<'_>, hooks: &Hooks, idx: usize) -> NonNull<T> {
+    hooks.try_get(idx).unwrap_or_else(|stored| {
+        // Count from the composable's own first hook, skipping hooks used internally to compose it.
+        let idx = idx.saturating_sub(cx.own_hook_idx.get());
+
+        let name = Runtime::current_name();
+        let name = name.as_deref().unwrap_or("composable");
+        panic!(
+            "Hook #{idx} in `{name}` changed type between compositions \
+            (from `{stored}` to `{}`): did you call a hook conditionally?",
+            core::any::type_name::<T>()
+        )
+    })
 }
 
-/// Use a mutable reference to a value of type `T`.
+/// Use a [`Cell`] containing a value of type `T`.
 ///
 /// `make_value` will only be called once to initialize this value.
-pub fn use_mut<T: 'static>(cx: ScopeState, make_value: impl FnOnce() -> T) -> SignalMut<'_, T> {
-    let hooks = unsafe { &mut *cx.hooks.get() };
+///
+/// Like [`use_ref_cell`], changes made through the returned cell don't re-compose this scope,
+/// so this is useful for transient scratch state (e.g. the last seen value of a `Copy` type).
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Counter;
+///
+/// impl Compose for Counter {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Count compositions without causing another one.
+///         let count = use_cell(&cx, || 0);
+///         count.set(count.get() + 1);
+///     }
+/// }
+/// ```
+pub fn use_cell<T: 'static>(cx: ScopeState<'_>, make_value: impl FnOnce() -> T) -> &Cell<T> {
+    use_ref(cx, || Cell::new(make_value()))
+}
 
-    let idx = cx.hook_idx.get();
-    cx.hook_idx.set(idx + 1);
+/// Use a [`RefCell`] containing a value of type `T`.
+///
+/// `make_value` will only be called once to initialize this value.
+///
+/// Changes made through the returned cell don't re-compose this scope,
+/// so this is useful for caches and other state that isn't rendered directly.
+/// For state that should re-compose this scope when it changes, prefer [`use_mut`].
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Counter;
+///
+/// impl Compose for Counter {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Count compositions without causing another one.
+///         let count = use_ref_cell(&cx, || 0);
+///         *count.borrow_mut() += 1;
+///     }
+/// }
+/// ```
+pub fn use_ref_cell<T: 'static>(cx: ScopeState<'_>, make_value: impl FnOnce() -> T) -> &RefCell<T> {
+    use_ref(cx, || RefCell::new(make_value()))
+}
 
-    let any = if idx >= hooks.len() {
-        let state = MutState {
-            value: make_value(),
-            generation: Cell::new(0),
-        };
-        hooks.push(Box::new(state));
-        hooks.last_mut().unwrap()
-    } else {
-        hooks.get_mut(idx).unwrap()
-    };
-    let state: &mut MutState<T> = any.downcast_mut().unwrap();
+/// Use a reference-counted value of type `T`.
+///
+/// `make_value` will only be called once to initialize this value,
+/// and each composition returns a new handle to the same value.
+/// This is useful for sharing a value with callbacks or tasks that must be `'static`.
+pub fn use_rc<T: 'static>(cx: ScopeState<'_>, make_value: impl FnOnce() -> T) -> Rc<T> {
+    use_ref(cx, || Rc::new(make_value())).clone()
+}
 
-    SignalMut {
-        ptr: unsafe { NonNull::new_unchecked(&mut state.value as *mut _) },
+pub(crate) struct MutState<T> {
+    pub(crate) value: T,
+    pub(crate) generation: Cell<u64>,
+}
+
+/// Use a two-way binding to a mutable value of type `T`.
+///
+/// Returns a [`Signal`] to read the current value,
+/// and a setter that writes back to `source` (only if the new value is different, to avoid update loops).
+/// This can be passed to input composables that take both a value and an on-change callback.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::rc::Rc;
+///
+/// #[derive(Data)]
+/// struct Checkbox<'a> {
+///     is_checked: Signal<'a, bool>,
+///     on_change: Rc<dyn Fn(bool) + 'a>,
+/// }
+///
+/// impl Compose for Checkbox<'_> {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_checked = *cx.me().is_checked;
+///         (cx.me().on_change)(!is_checked);
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_checked = use_mut(&cx, || false);
+///         let (is_checked, on_change) = use_model(&cx, is_checked);
+///
+///         Checkbox {
+///             is_checked,
+///             on_change,
+///         }
+///     }
+/// }
+/// ```
+pub fn use_model<'a, T>(
+    cx: ScopeState<'a>,
+    source: SignalMut<'a, T>,
+) -> (Signal<'a, T>, Rc<dyn Fn(T) + 'a>)
+where
+    T: PartialEq + Send + 'static,
+{
+    let set_value = use_callback(cx, move |value| SignalMut::set_if_neq(source, value));
+
+    (SignalMut::as_ref(source), set_value.clone())
+}
+
+/// Use a flag that is `true` only on the first composition of this scope.
+///
+/// This can be used to run effects (like an enter animation) when a composable is mounted.
+pub fn use_is_initial(cx: ScopeState<'_>) -> bool {
+    use_cell(cx, || true).replace(false)
+}
+
+/// Use a flag that is `true` if `value` has changed since the last composition of this scope.
+///
+/// This is `false` on the first composition (see [`use_is_initial`]).
+/// Unlike [`use_effect`], this only returns a flag, so it can be combined with other conditions
+/// (or used once for each field of a composable).
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct User {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// impl Compose for User {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         if use_changed(&cx, &cx.me().id) {
+///             dbg!("Switched users");
+///         }
+///
+///         if use_changed(&cx, &cx.me().name) {
+///             dbg!("Renamed");
+///         }
+///     }
+/// }
+/// ```
+pub fn use_changed<T>(cx: ScopeState<'_>, value: &T) -> bool
+where
+    T: PartialEq + Clone + 'static,
+{
+    let last = use_ref(cx, || RefCell::new(value.clone()));
+
+    if *last.borrow() != *value {
+        *last.borrow_mut() = value.clone();
+        true
+    } else {
+        false
+    }
+}
+
+/// Use a function that queues a re-composition of this scope.
+///
+/// This can be used to re-compose when polling external state that isn't stored in a [`SignalMut`].
+/// Like [`SignalMut::update`], the re-composition is queued with the runtime's updates.
+/// Calling the returned function after this scope is dropped does nothing.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::{cell::Cell, rc::Rc};
+///
+/// #[derive(Data)]
+/// struct Poll {
+///     value: Rc<Cell<i32>>,
+/// }
+///
+/// impl Compose for Poll {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let recompose = use_recompose(&cx);
+///
+///         let last = use_ref(&cx, || Cell::new(cx.me().value.get()));
+///         if last.replace(cx.me().value.get()) != cx.me().value.get() {
+///             recompose();
+///         }
+///     }
+/// }
+/// ```
+pub fn use_recompose(cx: ScopeState<'_>) -> Rc<dyn Fn()> {
+    use_ref(cx, || {
+        let scope_key = Runtime::current().current_key.get();
+
+        Rc::new(move || {
+            Runtime::current().update(move || {
+                let rt = Runtime::current();
+
+                // Do nothing if this scope was dropped.
+                if rt.nodes.borrow().contains_key(scope_key) {
+                    rt.queue(scope_key);
+                }
+            })
+        }) as Rc<dyn Fn()>
+    })
+    .clone()
+}
+
+/// Use a mutable reference to a value of type `T`.
+///
+/// `make_value` will only be called once to initialize this value.
+pub fn use_mut<T: 'static>(cx: ScopeState<'_>, make_value: impl FnOnce() -> T) -> SignalMut<'_, T> {
+    let hooks = unsafe { &mut *cx.hooks.get() };
+
+    let idx = cx.hook_idx.get();
+    cx.hook_idx.set(idx + 1);
+
+    let state: NonNull<MutState<T>> = if idx >= hooks.len() {
+        hooks.push(MutState {
+            value: make_value(),
+            generation: Cell::new(0),
+        })
+    } else {
+        get_hook(cx, hooks, idx)
+    };
+    let state = state.as_ptr();
+
+    // Safety: Hook values are pinned in their scope's arena.
+    SignalMut {
+        ptr: unsafe { NonNull::new_unchecked(core::ptr::addr_of_mut!((*state).value)) },
         scope_key: Runtime::current().current_key.get(),
-        generation: &state.generation,
+        generation: unsafe { core::ptr::addr_of!((*state).generation) },
         _marker: PhantomData,
     }
 }
 
-/// Use a callback function.
-/// The returned function will be updated to `f` whenever this component is re-composed.
-pub fn use_callback<'a, T, R>(
+macro_rules! impl_use_callback {
+    ($(#[$attr:meta])* $name:ident, $($t:ident: $input:ident),*) => {
+        $(#[$attr])*
+        pub fn $name<'a, $($t,)* R>(
+            cx: ScopeState<'a>,
+            f: impl FnMut($($t),*) -> R + 'a,
+        ) -> &'a Rc<dyn Fn($($t),*) -> R + 'a>
+        where
+            $($t: 'static,)*
+            R: 'static,
+        {
+            let f_cell: Option<Box<dyn FnMut($($t),*) -> R + 'a>> = Some(Box::new(f));
+            let mut f_cell: Option<Box<dyn FnMut($($t),*) -> R>> = unsafe { mem::transmute(f_cell) };
+
+            let callback = use_ref(cx, || Rc::new(RefCell::new(f_cell.take().unwrap()))).clone();
+
+            if let Some(f) = f_cell {
+                *callback.borrow_mut() = f;
+            }
+
+            use_ref(cx, move || {
+                let f = callback.clone();
+                Rc::new(move |$($input),*| f.borrow_mut()($($input),*)) as Rc<dyn Fn($($t),*) -> R>
+            })
+        }
+    };
+}
+
+impl_use_callback!(
+    /// Use a callback function.
+    /// The returned function will be updated to `f` whenever this component is re-composed.
+    use_callback,
+    T: input
+);
+
+impl_use_callback!(
+    /// Use a callback function that takes no arguments.
+    /// The returned function will be updated to `f` whenever this component is re-composed.
+    ///
+    /// For more see [`use_callback`].
+    use_callback0,
+);
+
+impl_use_callback!(
+    /// Use a callback function that takes two arguments.
+    /// The returned function will be updated to `f` whenever this component is re-composed.
+    ///
+    /// For more see [`use_callback`].
+    use_callback2,
+    T1: a,
+    T2: b
+);
+
+impl_use_callback!(
+    /// Use a callback function that takes three arguments.
+    /// The returned function will be updated to `f` whenever this component is re-composed.
+    ///
+    /// For more see [`use_callback`].
+    use_callback3,
+    T1: a,
+    T2: b,
+    T3: c
+);
+
+impl_use_callback!(
+    /// Use a callback function that takes four arguments.
+    /// The returned function will be updated to `f` whenever this component is re-composed.
+    ///
+    /// For more see [`use_callback`].
+    use_callback4,
+    T1: a,
+    T2: b,
+    T3: c,
+    T4: d
+);
+
+/// Use a callback function that is only updated when `dependency` changes.
+///
+/// Unlike [`use_callback`], the captured closure is kept from the composition where `dependency` last changed,
+/// so both the returned [`Rc`] and the function it calls stay the same until then.
+/// This makes callbacks passed to a [`Memo`](compose::Memo)-wrapped child behave predictably.
+///
+/// Any values `f` captures that can change between compositions should be included in `dependency`,
+/// or read through hooks (like a [`SignalMut`]) when the callback is called.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::rc::Rc;
+///
+/// #[derive(Data)]
+/// struct Button<'a> {
+///     on_click: Rc<dyn Fn(()) + 'a>,
+/// }
+///
+/// impl Compose for Button<'_> {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         (cx.me().on_click)(());
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App {
+///     step: i32,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let count = use_mut(&cx, || 0);
+///
+///         let step = cx.me().step;
+///         let on_click = use_callback_memo(&cx, step, move |()| {
+///             SignalMut::update(count, move |count| *count += step)
+///         });
+///
+///         Button {
+///             on_click: on_click.clone(),
+///         }
+///     }
+/// }
+/// ```
+pub fn use_callback_memo<'a, D, T, R>(
     cx: ScopeState<'a>,
+    dependency: D,
     f: impl FnMut(T) -> R + 'a,
 ) -> &'a Rc<dyn Fn(T) -> R + 'a>
 where
+    D: PartialEq + 'static,
     T: 'static,
     R: 'static,
 {
     let f_cell: Option<Box<dyn FnMut(T) -> R + 'a>> = Some(Box::new(f));
     let mut f_cell: Option<Box<dyn FnMut(T) -> R>> = unsafe { mem::transmute(f_cell) };
+    let mut dependency_cell = Some(dependency);
 
     let callback = use_ref(cx, || Rc::new(RefCell::new(f_cell.take().unwrap()))).clone();
+    let last = use_ref(cx, || RefCell::new(dependency_cell.take().unwrap()));
 
-    if let Some(f) = f_cell {
-        *callback.borrow_mut() = f;
+    if let (Some(dependency), Some(f)) = (dependency_cell, f_cell) {
+        let mut last = last.borrow_mut();
+        if dependency != *last {
+            *last = dependency;
+            *callback.borrow_mut() = f;
+        }
     }
 
     use_ref(cx, move || {
@@ -774,23 +1664,89 @@ impl<T> fmt::Display for ContextError<T> {
     }
 }
 
+/// Context values of type `T` kept alive by a scope.
+///
+/// Content from the last composition can still borrow the previous value,
+/// so values are stored in two slots, and a new value replaces the slot before the current one.
+struct ContextValues<T> {
+    slots: [UnsafeCell<Option<Rc<T>>>; 2],
+    current: Cell<usize>,
+}
+
+impl<T> ContextValues<T> {
+    fn new(value: Option<Rc<T>>) -> Self {
+        Self {
+            slots: [UnsafeCell::new(value), UnsafeCell::new(None)],
+            current: Cell::new(0),
+        }
+    }
+
+    /// Replace the current value, dropping the value before it.
+    fn push(&self, value: Rc<T>) {
+        let idx = 1 - self.current.get();
+
+        // Safety: This slot holds the value before the current one,
+        // which is no longer borrowed by content from the last composition.
+        unsafe { *self.slots[idx].get() = Some(value) };
+        self.current.set(idx);
+    }
+
+    fn current(&self) -> Option<&Rc<T>> {
+        // Safety: The current slot is only replaced after the next value is pushed.
+        unsafe { &*self.slots[self.current.get()].get() }.as_ref()
+    }
+}
+
 /// Use a context value of type `T`.
 ///
 /// This context must have already been provided by a parent composable with [`use_provider`],
 /// otherwise this function will return a [`ContextError`].
-pub fn use_context<T: 'static>(cx: ScopeState) -> Result<&Rc<T>, ContextError<T>> {
-    let result = use_ref(cx, || {
-        let Some(any) = cx.contexts.borrow().values.get(&TypeId::of::<T>()).cloned() else {
-            return Err(ContextError {
-                _marker: PhantomData,
-            });
-        };
+///
+/// Values provided by this composable's own [`use_provider`] are only visible to its children,
+/// so this always returns the parent's value (even if called after [`use_provider`]).
+/// This allows for composables that override a context value from their parent:
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// struct Depth(usize);
+///
+/// #[derive(Data)]
+/// struct Nested;
+///
+/// impl Compose for Nested {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let parent_depth = use_context::<Depth>(&cx).map(|depth| depth.0).unwrap_or(0);
+///         use_provider(&cx, || Depth(parent_depth + 1));
+///     }
+/// }
+/// ```
+pub fn use_context<T: 'static>(cx: ScopeState<'_>) -> Result<&Rc<T>, ContextError<T>> {
+    // Contexts can be re-provided by `use_provider_memo`, and the last value may still be borrowed.
+    let values: &ContextValues<T> = use_ref(cx, || {
+        // This scope reads the whole value, so re-compose it on every change.
+        cx.context_subscribers
+            .borrow_mut()
+            .push((TypeId::of::<T>(), Rc::new(|_| true)));
 
-        let value: Rc<T> = Rc::downcast(any).unwrap();
-        Ok(value)
+        ContextValues::new(None)
     });
 
-    result.as_ref().map_err(|e| *e)
+    let Some(any) = cx.contexts.borrow().values.get(&TypeId::of::<T>()).cloned() else {
+        return Err(ContextError {
+            _marker: PhantomData,
+        });
+    };
+
+    let is_changed = values
+        .current()
+        .map(|last| Rc::as_ptr(last) as *const () != Rc::as_ptr(&any) as *const ())
+        .unwrap_or(true);
+    if is_changed {
+        values.push(Rc::downcast(any).unwrap());
+    }
+
+    Ok(values.current().unwrap())
 }
 
 /// Provide a context value of type `T`.
@@ -807,89 +1763,716 @@ pub fn use_provider<T: 'static>(cx: ScopeState<'_>, make_value: impl FnOnce() ->
     })
 }
 
-/// Generational reference.
-/// This can be used to compare expensive values by pointer equality.
+/// Context providers for [`use_providers`].
+///
+/// This is implemented for tuples of functions that each return a context value.
+pub trait Providers<'a> {
+    /// References to each provided value.
+    type Output;
+
+    /// Provide each context value with [`use_provider`].
+    fn use_providers(self, cx: ScopeState<'a>) -> Self::Output;
+}
+
+macro_rules! impl_providers {
+    ($($f:ident: $t:ident),*) => {
+        impl<'a, $($f, $t),*> Providers<'a> for ($($f,)*)
+        where
+            $($f: FnOnce() -> $t, $t: 'static,)*
+        {
+            type Output = ($(&'a Rc<$t>,)*);
+
+            fn use_providers(self, cx: ScopeState<'a>) -> Self::Output {
+                #[allow(non_snake_case)]
+                let ($($f,)*) = self;
+                ($(use_provider(cx, $f),)*)
+            }
+        }
+    };
+}
+
+impl_providers!(F1: T1);
+impl_providers!(F1: T1, F2: T2);
+impl_providers!(F1: T1, F2: T2, F3: T3);
+impl_providers!(F1: T1, F2: T2, F3: T3, F4: T4);
+impl_providers!(F1: T1, F2: T2, F3: T3, F4: T4, F5: T5);
+impl_providers!(F1: T1, F2: T2, F3: T3, F4: T4, F5: T5, F6: T6);
+impl_providers!(F1: T1, F2: T2, F3: T3, F4: T4, F5: T5, F6: T6, F7: T7);
+impl_providers!(F1: T1, F2: T2, F3: T3, F4: T4, F5: T5, F6: T6, F7: T7, F8: T8);
+
+/// Provide multiple context values at once, from a tuple of functions that each make a value.
+///
+/// This is the same as calling [`use_provider`] for each function, in order:
+/// each value is only made on the first composition, and a later value of the same type overrides an earlier one.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// struct Theme;
+///
+/// struct Locale(&'static str);
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let (_theme, locale) = use_providers(&cx, (|| Theme, || Locale("en")));
+///         dbg!(locale.0);
+///     }
+/// }
+/// ```
+pub fn use_providers<'a, P: Providers<'a>>(cx: ScopeState<'a>, providers: P) -> P::Output {
+    providers.use_providers(cx)
+}
+
+/// Provide a memoized context value of type `T` with a dependency of type `D`.
+///
+/// `make_value` will re-provide a new value whenever `dependency` is changed.
+/// Children that already use this context are then re-composed with the new value
+/// (each keeps the previous value alive until the next change).
+/// Children using [`use_selector`] are only re-composed if their selected value changed.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// struct Config {
+///     is_dark: bool,
+/// }
+///
+/// #[derive(Data)]
+/// struct App {
+///     is_dark: bool,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_dark = cx.me().is_dark;
+///         use_provider_memo(&cx, is_dark, || Config { is_dark });
+///     }
+/// }
+/// ```
+pub fn use_provider_memo<D, T>(
+    cx: ScopeState<'_>,
+    dependency: D,
+    make_value: impl FnOnce() -> T,
+) -> &Rc<T>
+where
+    D: PartialEq + 'static,
+    T: 'static,
+{
+    let mut dependency_cell = Some(dependency);
+    let mut make_value_cell = Some(make_value);
+
+    let (last_dependency, values): &(RefCell<D>, ContextValues<T>) = use_ref(cx, || {
+        let value = Rc::new(make_value_cell.take().unwrap()());
+        cx.child_contexts
+            .borrow_mut()
+            .values
+            .insert(TypeId::of::<T>(), value.clone());
+
+        (
+            RefCell::new(dependency_cell.take().unwrap()),
+            ContextValues::new(Some(value)),
+        )
+    });
+
+    if let Some(make_value) = make_value_cell {
+        let dependency = dependency_cell.take().unwrap();
+
+        if dependency != *last_dependency.borrow() {
+            *last_dependency.borrow_mut() = dependency;
+
+            let value = Rc::new(make_value());
+            let any: Rc<dyn Any> = value.clone();
+            values.push(value);
+
+            let last = cx
+                .child_contexts
+                .borrow_mut()
+                .values
+                .insert(TypeId::of::<T>(), any.clone())
+                .unwrap();
+
+            // Replace the last value in each descendant that inherited it,
+            // stopping at descendants that override this context.
+            let rt = Runtime::current();
+            let mut keys = Vec::new();
+            {
+                let nodes = rt.nodes.borrow();
+                let mut stack = nodes[rt.current_key.get()].children.borrow().clone();
+
+                while let Some(key) = stack.pop() {
+                    let Some(node) = nodes.get(key) else {
+                        continue;
+                    };
+
+                    let mut contexts = node.scope.contexts.borrow_mut();
+                    let Some(value) = contexts.values.get_mut(&TypeId::of::<T>()) else {
+                        continue;
+                    };
+                    if !Rc::ptr_eq(value, &last) {
+                        continue;
+                    }
+                    *value = any.clone();
+
+                    // Only re-compose descendants that read this context.
+                    let is_subscribed = node
+                        .scope
+                        .context_subscribers
+                        .borrow()
+                        .iter()
+                        .filter(|(id, _)| *id == TypeId::of::<T>())
+                        .fold(false, |is_subscribed, (_, f)| f(&any) || is_subscribed);
+                    if is_subscribed {
+                        keys.push(key);
+                    }
+
+                    stack.extend(node.children.borrow().iter().copied());
+                }
+            }
+
+            for key in keys {
+                rt.queue(key);
+            }
+        }
+    }
+
+    values.current().unwrap()
+}
+
+/// Use a computed value of type `T` with a dependency of type `D`, shared with children as a context.
+///
+/// `compute` is only called when `dependency` has changed, and the result is provided to
+/// [`use_context`] (or [`use_selector`]) in all children of this composable.
+/// Children are only re-composed when the computed value itself has changed,
+/// so many descendants can share one derived value without each re-computing it.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Total(i32);
+///
+/// #[derive(Data)]
+/// struct Row;
+///
+/// impl Compose for Row {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let total = use_context::<Total>(&cx).unwrap();
+///         dbg!(total.0);
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct Table {
+///     values: Vec<i32>,
+/// }
+///
+/// impl Compose for Table {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let values = cx.me().values.clone();
+///         use_computed(&cx, values.clone(), || Total(values.iter().sum()));
+///
+///         (Row, Row)
+///     }
+/// }
+/// ```
+pub fn use_computed<D, T>(cx: ScopeState<'_>, dependency: D, compute: impl FnOnce() -> T) -> &Rc<T>
+where
+    D: PartialEq + 'static,
+    T: Clone + PartialEq + 'static,
+{
+    let value = use_memo_local(cx, dependency, compute);
+    use_provider_memo(cx, value.clone(), || value.clone())
+}
+
+/// Use a value selected from a context of type `T`.
+///
+/// `select` is called with the current context value on each composition.
+/// When the context is re-provided (see [`use_provider_memo`]),
+/// this scope is only re-composed if the selected value has changed.
+///
+/// # Panics
+/// Panics if a context of type `T` was not provided by a parent composable.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// struct Settings {
+///     volume: f32,
+///     is_muted: bool,
+/// }
+///
+/// #[derive(Data)]
+/// struct MuteButton;
+///
+/// impl Compose for MuteButton {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Changes to `volume` won't re-compose this scope.
+///         let is_muted = use_selector(&cx, |settings: &Settings| settings.is_muted);
+///
+///         dbg!(is_muted);
+///     }
+/// }
+/// ```
+pub fn use_selector<T, U>(cx: ScopeState<'_>, select: fn(&T) -> U) -> U
+where
+    T: 'static,
+    U: PartialEq + Clone + 'static,
+{
+    let last: &Rc<RefCell<Option<U>>> = use_ref(cx, || {
+        let last = Rc::new(RefCell::new(None::<U>));
+
+        let subscriber_last = last.clone();
+        cx.context_subscribers.borrow_mut().push((
+            TypeId::of::<T>(),
+            Rc::new(move |any| {
+                let value = (**any).downcast_ref::<T>().unwrap();
+                subscriber_last.borrow().as_ref() != Some(&select(value))
+            }),
+        ));
+
+        last
+    });
+
+    let Some(any) = cx.contexts.borrow().values.get(&TypeId::of::<T>()).cloned() else {
+        panic!(
+            "Context value not found for type: {}",
+            core::any::type_name::<T>()
+        );
+    };
+
+    let selected = select((*any).downcast_ref::<T>().unwrap());
+    *last.borrow_mut() = Some(selected.clone());
+    selected
+}
+
+/// Generational reference.
+/// This can be used to compare expensive values by pointer equality.
+///
+/// This trait is implemented for:
+/// - [`Signal`]
+/// - [`Map`]
+/// - [`SignalMut`]
+pub trait Generational {
+    /// Get the current generation of this value.
+    fn generation(self) -> u64;
+}
+
+impl<T> Generational for Signal<'_, T> {
+    fn generation(self) -> u64 {
+        // Safety: This pointer is valid for `'a`.
+        unsafe { &*self.generation }.get()
+    }
+}
+
+impl<T> Generational for Map<'_, T> {
+    fn generation(self) -> u64 {
+        // Safety: This pointer is valid for `'a`.
+        unsafe { &*self.generation }.get()
+    }
+}
+
+impl<T> Generational for SignalMut<'_, T> {
+    fn generation(self) -> u64 {
+        // Safety: This pointer is valid for `'a`.
+        unsafe { &*self.generation }.get()
+    }
+}
+
+/// Use an effect that will run whenever the provided dependency is changed.
+pub fn use_effect<D, T>(cx: ScopeState<'_>, dependency: D, effect: impl FnOnce(&D))
+where
+    D: PartialEq + Send + 'static,
+{
+    let mut dependency_cell = Some(dependency);
+
+    let last_mut = use_mut(cx, || dependency_cell.take().unwrap());
+
+    if let Some(dependency) = dependency_cell.take() {
+        if dependency != *last_mut {
+            effect(&dependency);
+
+            SignalMut::set(last_mut, dependency);
+        }
+    } else {
+        effect(&last_mut);
+    }
+}
+
+/// Use a function that will be called after the current compose pass completes.
+///
+/// `f` is queued each time this scope is composed, and called once every pending composable has been composed,
+/// so it observes the committed tree (e.g. to read sizes or sync external state).
+/// This differs from [`use_effect`], which runs while this scope is composing.
+/// Updates to signals made from `f` are applied before the next pass.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Counter;
+///
+/// impl Compose for Counter {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let count = use_mut(&cx, || 0);
+///
+///         use_post_compose(&cx, move || {
+///             // Runs after `Label` has been composed.
+///             dbg!(*count);
+///         });
+///
+///         Label { count: *count }
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct Label {
+///     count: i32,
+/// }
+///
+/// impl Compose for Label {
+///     fn compose(cx: Scope<Self>) -> impl Compose {}
+/// }
+/// ```
+pub fn use_post_compose<'a>(_cx: ScopeState<'a>, f: impl FnOnce() + 'a) {
+    let f: Box<dyn FnOnce() + 'a> = Box::new(f);
+
+    // Safety: `f` is only called while this scope's node exists.
+    let f: Box<dyn FnOnce()> = unsafe { mem::transmute(f) };
+
+    Runtime::current().post_compose(f);
+}
+
+/// Use a memoized value of type `T` with a dependency of type `D`.
+///
+/// `make_value` will update the returned value whenver `dependency` is changed.
+pub fn use_memo<D, T>(
+    cx: ScopeState<'_>,
+    dependency: D,
+    make_value: impl FnOnce() -> T,
+) -> Signal<'_, T>
+where
+    D: PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    let mut dependency_cell = Some(dependency);
+    let mut make_value_cell = Some(make_value);
+
+    let value_mut = use_mut(cx, || make_value_cell.take().unwrap()());
+    let last_mut = use_mut(cx, || dependency_cell.take().unwrap());
+
+    if let Some(make_value) = make_value_cell {
+        if let Some(dependency) = dependency_cell.take() {
+            if dependency != *last_mut {
+                let value = make_value();
+                SignalMut::with(value_mut, move |update| *update = value);
+
+                SignalMut::with(last_mut, move |dst| *dst = dependency);
+            }
+        }
+    }
+
+    SignalMut::as_ref(value_mut)
+}
+
+/// Use a memoized value of type `T` with a floating-point dependency, quantized by `epsilon`.
+///
+/// `value` is floored to buckets of size `epsilon`,
+/// and `make_value` will only update the returned value when `value` moves into a different bucket.
+/// This avoids re-computing expensive values on small changes (e.g. from a smoothly animating position).
+/// All `NaN` values are treated as the same bucket.
+///
+/// # Panics
+/// Panics if `epsilon` is not greater than zero.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Layout {
+///     x: f64,
+/// }
+///
+/// impl Compose for Layout {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Only re-compute the column when `x` moves by roughly a full pixel.
+///         let column = use_memo_quantized(&cx, cx.me().x, 1., || (cx.me().x / 100.) as usize);
+///         dbg!(*column);
+///     }
+/// }
+/// ```
+pub fn use_memo_quantized<T>(
+    cx: ScopeState<'_>,
+    value: f64,
+    epsilon: f64,
+    make_value: impl FnOnce() -> T,
+) -> Signal<'_, T>
+where
+    T: Send + 'static,
+{
+    assert!(epsilon > 0., "Memo epsilon must be greater than zero.");
+
+    let bucket = if value.is_nan() {
+        None
+    } else {
+        Some((value / epsilon).floor() as i64)
+    };
+
+    use_memo(cx, bucket, make_value)
+}
+
+/// Use a memoized value of type `T` with a dependency of type `D`, computed on the current thread.
+///
+/// Unlike [`use_memo`], neither `T` nor `D` are required to be `Send`,
+/// as the value is recomputed immediately instead of through the update queue.
+/// `make_value` will update the returned value whenever `dependency` is changed.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// #[derive(Data)]
+/// struct App {
+///     query: String,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // `Rc` isn't `Send`, so this can't be used with `use_memo`.
+///         let results = use_memo_local(&cx, cx.me().query.clone(), || {
+///             Rc::new(RefCell::new(Vec::<String>::new()))
+///         });
+///         results.borrow_mut().push(cx.me().query.clone());
+///     }
+/// }
+/// ```
+pub fn use_memo_local<'a, D, T>(
+    cx: ScopeState<'a>,
+    dependency: D,
+    make_value: impl FnOnce() -> T,
+) -> &'a T
+where
+    D: PartialEq + 'static,
+    T: 'static,
+{
+    let mut dependency_cell = Some(dependency);
+    let mut make_value_cell = Some(make_value);
+
+    let cell = use_ref(cx, || {
+        UnsafeCell::new((
+            dependency_cell.take().unwrap(),
+            make_value_cell.take().unwrap()(),
+        ))
+    });
+
+    // Safety: The value is only replaced here, before being borrowed for this composition.
+    let (last, value) = unsafe { &mut *cell.get() };
+
+    if let (Some(dependency), Some(make_value)) = (dependency_cell, make_value_cell) {
+        if dependency != *last {
+            *value = make_value();
+            *last = dependency;
+        }
+    }
+
+    value
+}
+
+/// Use an immutable reference to a value of type `T` that is re-initialized when `key` changes.
+///
+/// Like [`use_ref`], `init` is called on the first composition.
+/// When `key` changes, `init` is called again and the previous value is dropped,
+/// and the returned reference points to the new value.
+///
+/// This is equivalent to [`use_memo_local`], named for identity-based state (like a cache per document).
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::{cell::RefCell, collections::HashMap};
+///
+/// #[derive(Data)]
+/// struct Document {
+///     id: u64,
+/// }
+///
+/// impl Compose for Document {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Reset the layout cache when the document changes.
+///         let layout_cache = use_ref_keyed(&cx, cx.me().id, || RefCell::new(HashMap::<usize, f32>::new()));
+///         layout_cache.borrow_mut().insert(0, 1.);
+///     }
+/// }
+/// ```
+pub fn use_ref_keyed<'a, K, T>(cx: ScopeState<'a>, key: K, init: impl FnOnce() -> T) -> &'a T
+where
+    K: PartialEq + 'static,
+    T: 'static,
+{
+    use_memo_local(cx, key, init)
+}
+
+/// Bounded history of a value, for undo and redo.
 ///
-/// This trait is implemented for:
-/// - [`Signal`]
-/// - [`Map`]
-/// - [`SignalMut`]
-pub trait Generational {
-    /// Get the current generation of this value.
-    fn generation(self) -> u64;
+/// For more see [`use_history`].
+pub struct History<T> {
+    entries: RefCell<alloc::collections::VecDeque<T>>,
+    cursor: Cell<usize>,
+    capacity: usize,
 }
 
-impl<T> Generational for Signal<'_, T> {
-    fn generation(self) -> u64 {
-        // Safety: This pointer is valid for `'a`.
-        unsafe { &*self.generation }.get()
+impl<T: Clone> History<T> {
+    /// Get the entries of this history, from oldest to newest.
+    ///
+    /// This includes entries that can be restored with [`History::redo`].
+    pub fn entries(&self) -> core::cell::Ref<'_, alloc::collections::VecDeque<T>> {
+        self.entries.borrow()
     }
-}
 
-impl<T> Generational for Map<'_, T> {
-    fn generation(self) -> u64 {
-        // Safety: This pointer is valid for `'a`.
-        unsafe { &*self.generation }.get()
+    /// Get the index of the current entry.
+    pub fn index(&self) -> usize {
+        self.cursor.get()
     }
-}
 
-impl<T> Generational for SignalMut<'_, T> {
-    fn generation(self) -> u64 {
-        // Safety: This pointer is valid for `'a`.
-        unsafe { &*self.generation }.get()
+    /// Get the current entry.
+    pub fn current(&self) -> T {
+        self.entries.borrow()[self.cursor.get()].clone()
     }
-}
 
-/// Use an effect that will run whenever the provided dependency is changed.
-pub fn use_effect<D, T>(cx: ScopeState, dependency: D, effect: impl FnOnce(&D))
-where
-    D: PartialEq + Send + 'static,
-{
-    let mut dependency_cell = Some(dependency);
+    /// Returns `true` if there is a previous entry to restore.
+    pub fn can_undo(&self) -> bool {
+        self.cursor.get() > 0
+    }
 
-    let last_mut = use_mut(cx, || dependency_cell.take().unwrap());
+    /// Returns `true` if there is a next entry to restore.
+    pub fn can_redo(&self) -> bool {
+        self.cursor.get() + 1 < self.entries.borrow().len()
+    }
 
-    if let Some(dependency) = dependency_cell.take() {
-        if dependency != *last_mut {
-            effect(&dependency);
+    /// Move back to the previous entry, returning it.
+    ///
+    /// The caller is responsible for applying the returned value to its source state.
+    pub fn undo(&self) -> Option<T> {
+        if !self.can_undo() {
+            return None;
+        }
 
-            SignalMut::set(last_mut, dependency);
+        self.cursor.set(self.cursor.get() - 1);
+        Some(self.current())
+    }
+
+    /// Move forward to the next entry, returning it.
+    ///
+    /// The caller is responsible for applying the returned value to its source state.
+    pub fn redo(&self) -> Option<T> {
+        if !self.can_redo() {
+            return None;
         }
-    } else {
-        effect(&last_mut);
+
+        self.cursor.set(self.cursor.get() + 1);
+        Some(self.current())
     }
 }
 
-/// Use a memoized value of type `T` with a dependency of type `D`.
+impl<T: fmt::Debug> fmt::Debug for History<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("History")
+            .field("entries", &self.entries.borrow())
+            .field("cursor", &self.cursor.get())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+/// Use a bounded history of `value`, for undo and redo.
 ///
-/// `make_value` will update the returned value whenver `dependency` is changed.
-pub fn use_memo<D, T>(cx: ScopeState, dependency: D, make_value: impl FnOnce() -> T) -> Signal<T>
+/// A new entry is pushed whenever `value` is different from the current entry,
+/// and the oldest entry is removed once the history holds more than `capacity` entries.
+/// Pushing an entry after calling [`History::undo`] discards any entries that could be restored with [`History::redo`].
+///
+/// # Panics
+/// Panics if `capacity` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Editor;
+///
+/// impl Compose for Editor {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let text = use_mut(&cx, String::new);
+///         let history = use_history(&cx, &*text, 100);
+///
+///         if let Some(last) = history.undo() {
+///             SignalMut::set(text, last);
+///         }
+///     }
+/// }
+/// ```
+pub fn use_history<'a, T>(cx: ScopeState<'a>, value: &T, capacity: usize) -> &'a History<T>
 where
-    D: PartialEq + Send + 'static,
-    T: Send + 'static,
+    T: Clone + PartialEq + 'static,
 {
-    let mut dependency_cell = Some(dependency);
-    let mut make_value_cell = Some(make_value);
+    assert!(capacity > 0, "History capacity must be greater than zero.");
 
-    let value_mut = use_mut(cx, || make_value_cell.take().unwrap()());
-    let last_mut = use_mut(cx, || dependency_cell.take().unwrap());
+    let history = use_ref(cx, || History {
+        entries: RefCell::new([value.clone()].into()),
+        cursor: Cell::new(0),
+        capacity,
+    });
 
-    if let Some(make_value) = make_value_cell {
-        if let Some(dependency) = dependency_cell.take() {
-            if dependency != *last_mut {
-                let value = make_value();
-                SignalMut::with(value_mut, move |update| *update = value);
+    let mut entries = history.entries.borrow_mut();
+    if entries[history.cursor.get()] != *value {
+        // Discard any entries after the current one.
+        entries.truncate(history.cursor.get() + 1);
+        entries.push_back(value.clone());
 
-                SignalMut::with(last_mut, move |dst| *dst = dependency);
-            }
+        if entries.len() > history.capacity {
+            entries.pop_front();
         }
+        history.cursor.set(entries.len() - 1);
     }
+    drop(entries);
 
-    SignalMut::as_ref(value_mut)
+    history
 }
 
 /// Use a function that will be called when this scope is dropped.
+///
+/// Child scopes are always dropped before their parent,
+/// so drop functions run from the leaves of the tree towards the root.
+/// Within a scope, drop functions run in the order they were registered
+/// (see [`use_drop_ordered`] for more control).
 pub fn use_drop<'a>(cx: ScopeState<'a>, f: impl FnOnce() + 'a) {
+    use_drop_ordered(cx, 0, f)
+}
+
+/// Use a function that will be called when this scope is dropped, with a `priority`.
+///
+/// Drop functions in the same scope with a higher priority are called first.
+/// Drop functions with equal priority are called in the order they were registered.
+/// [`use_drop`] registers a drop function with a priority of `0`.
+pub fn use_drop_ordered<'a>(cx: ScopeState<'a>, priority: i32, f: impl FnOnce() + 'a) {
     let mut f_cell = Some(f);
 
     let cell = use_ref(cx, || {
@@ -903,7 +2486,7 @@ pub fn use_drop<'a>(cx: ScopeState<'a>, f: impl FnOnce() + 'a) {
 
     let idx = cx.hook_idx.get();
     use_ref(cx, || {
-        cx.drops.borrow_mut().push(idx);
+        cx.drops.borrow_mut().push((priority, idx));
 
         let f: Box<dyn FnMut()> = Box::new(move || {
             cell.borrow_mut().take().unwrap()();
@@ -924,6 +2507,115 @@ pub fn use_drop<'a>(cx: ScopeState<'a>, f: impl FnOnce() + 'a) {
     }
 }
 
+/// Use a nested scope for hooks, keyed by `key`.
+///
+/// Hooks must normally be called in the same order on every composition.
+/// Hooks called inside `f` are instead tracked by a nested scope,
+/// so a branch can use a different set of hooks than the others.
+/// When `key` changes, the previous branch is dropped (running any [`use_drop`] functions)
+/// and a new branch is started with fresh hook state.
+/// For the same reason, values returned from `f` can't borrow from hooks in the branch.
+///
+/// Context values provided inside of a branch are not visible to child composables.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct App {
+///     is_editing: bool,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_editing = cx.me().is_editing;
+///
+///         // Each branch can safely use its own hooks.
+///         use_branch(&cx, is_editing, |cx| {
+///             if is_editing {
+///                 let draft = use_mut(cx, String::new);
+///                 dbg!(&*draft);
+///             } else {
+///                 let views = use_ref(cx, || std::cell::Cell::new(0));
+///                 views.set(views.get() + 1);
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub fn use_branch<K, R>(
+    cx: ScopeState<'_>,
+    key: K,
+    f: impl for<'b> FnOnce(ScopeState<'b>) -> R,
+) -> R
+where
+    K: PartialEq + 'static,
+{
+    let cell: &RefCell<Option<(K, Box<ScopeData<'static>>)>> = use_ref(cx, || RefCell::new(None));
+
+    let mut branch = cell.borrow_mut();
+    let last = if branch
+        .as_ref()
+        .map(|(last, _)| *last != key)
+        .unwrap_or(true)
+    {
+        let scope = ScopeData::default();
+        *scope.contexts.borrow_mut() = cx.contexts.borrow().clone();
+
+        branch.replace((key, Box::new(scope)))
+    } else {
+        None
+    };
+
+    let scope: &ScopeData = &branch.as_ref().unwrap().1;
+    scope.hook_idx.set(0);
+    scope.generation.set(scope.generation.get() + 1);
+
+    // Safety: The branch scope is boxed, and only dropped when this scope is dropped or the branch changes.
+    // `f` is generic over the lifetime of the branch, so nothing borrowed from it can be returned.
+    let scope: ScopeState = unsafe { mem::transmute(scope) };
+
+    drop(branch);
+
+    // Drop the previous branch (and run its drop functions) before composing the new one.
+    drop(last);
+
+    f(scope)
+}
+
+/// Use a notification that sends `key` to `tx` when this scope is dropped.
+///
+/// The notification is sent from [`use_drop`], so it follows the same drop order
+/// (i.e. after any child scopes are dropped).
+/// If the receiver has already been dropped, the notification is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::sync::mpsc;
+///
+/// #[derive(Data)]
+/// struct Item {
+///     id: usize,
+///     tx: mpsc::Sender<usize>,
+/// }
+///
+/// impl Compose for Item {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         use_unmount_notify(&cx, &cx.me().tx, cx.me().id);
+///     }
+/// }
+/// ```
+pub fn use_unmount_notify<'a, K: 'a>(cx: ScopeState<'a>, tx: &std::sync::mpsc::Sender<K>, key: K) {
+    let tx = tx.clone();
+    use_drop(cx, move || {
+        let _ = tx.send(key);
+    });
+}
+
 /// Use a local task that runs on the current thread.
 ///
 /// This will run on the window event loop, polling the task until it completes.
@@ -992,6 +2684,53 @@ where
     })
 }
 
+/// Use the latest item of a [`Stream`](futures::Stream), polled as a local task.
+///
+/// `make_stream` is called once to create the stream,
+/// which is then polled on the current thread (see [`use_local_task`]).
+/// This scope is re-composed when a new item is received, and the returned [`Signal`] is `None` until the first item.
+/// Items that are ready at the same time are coalesced, so only the latest item is composed.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use futures::stream;
+///
+/// #[derive(Data)]
+/// struct Progress;
+///
+/// impl Compose for Progress {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let progress = use_stream(&cx, || stream::iter([25, 50, 75, 100]));
+///
+///         if let Some(progress) = *progress {
+///             dbg!(progress);
+///         }
+///     }
+/// }
+/// ```
+pub fn use_stream<'a, S>(
+    cx: ScopeState<'a>,
+    make_stream: impl FnOnce() -> S + 'a,
+) -> Signal<'a, Option<S::Item>>
+where
+    S: futures::Stream + 'a,
+    S::Item: Send + 'static,
+{
+    let latest = use_mut(cx, || None);
+
+    use_local_task(cx, move || async move {
+        let mut stream = core::pin::pin!(make_stream());
+
+        while let Some(item) = futures::StreamExt::next(&mut stream).await {
+            SignalMut::set(latest, Some(item));
+        }
+    });
+
+    SignalMut::as_ref(latest)
+}
+
 #[cfg(feature = "executor")]
 type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
 
@@ -1121,3 +2860,234 @@ where
         *task_lock.lock().unwrap() = None;
     });
 }
+
+#[cfg(feature = "rt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+/// Use a debounced value of type `T`.
+///
+/// The returned value is only updated to `value` after it has stopped changing for `delay`.
+/// Changes made before the delay has passed will restart the timer.
+///
+/// This requires an [`ExecutorContext`](`crate::executor::ExecutorContext`) running on the Tokio runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use std::time::Duration;
+///
+/// #[derive(Data)]
+/// struct Search {
+///     query: String,
+/// }
+///
+/// impl Compose for Search {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let query = use_debounce(&cx, cx.me().query.clone(), Duration::from_millis(300));
+///
+///         dbg!(&*query);
+///     }
+/// }
+/// ```
+pub fn use_debounce<T>(cx: ScopeState<'_>, value: T, delay: core::time::Duration) -> Signal<'_, T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let out = use_mut(cx, || value.clone());
+
+    let (tx, rx_cell) = use_ref(cx, || {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        (tx, Cell::new(Some(rx)))
+    });
+
+    use_task(cx, move || {
+        let mut rx: tokio::sync::mpsc::UnboundedReceiver<T> = rx_cell.take().unwrap();
+
+        async move {
+            while let Some(mut value) = rx.recv().await {
+                // Restart the timer whenever a new value is received.
+                loop {
+                    match tokio::time::timeout(delay, rx.recv()).await {
+                        Ok(Some(next)) => value = next,
+                        Ok(None) => return,
+                        Err(_) => break,
+                    }
+                }
+
+                SignalMut::set_if_neq(out, value);
+            }
+        }
+    });
+
+    let last = use_ref(cx, || RefCell::new(value.clone()));
+    if *last.borrow() != value {
+        *last.borrow_mut() = value.clone();
+        tx.send(value).unwrap();
+    }
+
+    SignalMut::as_ref(out)
+}
+
+#[cfg(feature = "rt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+/// Use a throttled value of type `T`.
+///
+/// The first change to `value` is emitted immediately,
+/// then further changes are held back until `min_interval` has passed.
+/// The latest held-back value is emitted at the end of each interval.
+///
+/// This requires an [`ExecutorContext`](`crate::executor::ExecutorContext`) running on the Tokio runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use std::time::Duration;
+///
+/// #[derive(Data)]
+/// struct Slider {
+///     position: f32,
+/// }
+///
+/// impl Compose for Slider {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let position = use_throttle(&cx, cx.me().position, Duration::from_millis(100));
+///
+///         dbg!(*position);
+///     }
+/// }
+/// ```
+pub fn use_throttle<T>(
+    cx: ScopeState<'_>,
+    value: T,
+    min_interval: core::time::Duration,
+) -> Signal<'_, T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let out = use_mut(cx, || value.clone());
+
+    let (tx, rx_cell) = use_ref(cx, || {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        (tx, Cell::new(Some(rx)))
+    });
+
+    use_task(cx, move || {
+        let mut rx: tokio::sync::mpsc::UnboundedReceiver<T> = rx_cell.take().unwrap();
+
+        async move {
+            while let Some(value) = rx.recv().await {
+                // Emit the leading edge immediately.
+                SignalMut::set_if_neq(out, value);
+
+                loop {
+                    let deadline = tokio::time::Instant::now() + min_interval;
+
+                    let mut latest = None;
+                    let is_closed = loop {
+                        match tokio::time::timeout_at(deadline, rx.recv()).await {
+                            Ok(Some(next)) => latest = Some(next),
+                            Ok(None) => break true,
+                            Err(_) => break false,
+                        }
+                    };
+
+                    // Emit the trailing edge with the latest value (if any).
+                    let Some(value) = latest else {
+                        if is_closed {
+                            return;
+                        }
+                        break;
+                    };
+                    SignalMut::set_if_neq(out, value);
+
+                    if is_closed {
+                        return;
+                    }
+                }
+            }
+        }
+    });
+
+    let last = use_ref(cx, || RefCell::new(value.clone()));
+    if *last.borrow() != value {
+        *last.borrow_mut() = value.clone();
+        tx.send(value).unwrap();
+    }
+
+    SignalMut::as_ref(out)
+}
+
+#[cfg(feature = "rt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+/// Handle to a timeout created with [`use_timeout`].
+#[derive(Debug, Default)]
+pub struct Timeout {
+    is_cancelled: Cell<bool>,
+    is_done: Cell<bool>,
+}
+
+#[cfg(feature = "rt")]
+impl Timeout {
+    /// Cancel this timeout, if it hasn't already fired.
+    pub fn cancel(&self) {
+        self.is_cancelled.set(true);
+    }
+
+    /// Returns `true` if this timeout has neither fired nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        !self.is_done.get() && !self.is_cancelled.get()
+    }
+}
+
+#[cfg(feature = "rt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+/// Use a timeout that calls `f` once, `delay` after this scope is first composed.
+///
+/// The timer starts on the first composition, so re-composing this scope won't restart it.
+/// After the delay, this scope is re-composed and the latest `f` is called.
+/// The timer is cancelled when this scope is dropped, or early with [`Timeout::cancel`].
+///
+/// This requires an [`ExecutorContext`](`crate::executor::ExecutorContext`) running on the Tokio runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use std::time::Duration;
+///
+/// #[derive(Data)]
+/// struct Toast<'a> {
+///     is_open: SignalMut<'a, bool>,
+/// }
+///
+/// impl Compose for Toast<'_> {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_open = cx.me().is_open;
+///         use_timeout(&cx, Duration::from_secs(3), || SignalMut::set(is_open, false));
+///     }
+/// }
+/// ```
+pub fn use_timeout<'a>(
+    cx: ScopeState<'a>,
+    delay: core::time::Duration,
+    f: impl FnOnce(),
+) -> &'a Timeout {
+    let is_elapsed = use_mut(cx, || false);
+
+    use_task(cx, move || async move {
+        tokio::time::sleep(delay).await;
+        SignalMut::set(is_elapsed, true);
+    });
+
+    let timeout = use_ref(cx, Timeout::default);
+    if *is_elapsed && !timeout.is_done.get() {
+        timeout.is_done.set(true);
+
+        if !timeout.is_cancelled.get() {
+            f();
+        }
+    }
+
+    timeout
+}
@@ -0,0 +1,149 @@
+use crate::{
+    compose::Compose,
+    composer::{Composer, TryComposeError},
+};
+use core::{error::Error, fmt};
+
+/// Create a [`TestHarness`] for testing composable `content`.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::{prelude::*, test_util::{test_compose, SignalId}};
+///
+/// #[derive(Data)]
+/// struct Child;
+///
+/// impl Compose for Child {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {}
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_shown = use_mut(&cx, || false);
+///
+///         if *is_shown {
+///             Some(Child)
+///         } else {
+///             None
+///         }
+///     }
+/// }
+///
+/// let mut harness = test_compose(App);
+/// harness.compose();
+/// harness.assert_tree("Composer(App(Option))");
+///
+/// // Set the first hook (`is_shown`) of the root composable.
+/// harness.set(SignalId::root(0), true);
+/// harness.compose();
+/// harness.assert_tree("Composer(App(Option(Child)))");
+/// ```
+pub fn test_compose(content: impl Compose + 'static) -> TestHarness {
+    TestHarness {
+        composer: Composer::new(content),
+    }
+}
+
+/// Identifier of a [`use_mut`](crate::use_mut) hook in a composition.
+///
+/// For more see [`TestHarness::set`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignalId {
+    path: Vec<usize>,
+    hook_idx: usize,
+}
+
+impl SignalId {
+    /// Create a new signal ID for the hook at `hook_idx` in the composable at `path`.
+    ///
+    /// The path is the list of child indices from the root composable (as shown in [`TestHarness::tree`]),
+    /// and the hook index counts every hook (e.g. `use_ref`, `use_mut`) in call order.
+    pub fn new(path: impl Into<Vec<usize>>, hook_idx: usize) -> Self {
+        Self {
+            path: path.into(),
+            hook_idx,
+        }
+    }
+
+    /// Create a new signal ID for the hook at `hook_idx` in the root composable.
+    pub fn root(hook_idx: usize) -> Self {
+        Self::new(Vec::new(), hook_idx)
+    }
+}
+
+/// Harness for testing composables.
+///
+/// For more see [`test_compose`].
+pub struct TestHarness {
+    composer: Composer,
+}
+
+impl TestHarness {
+    /// Compose a single pending node, returning `false` if nothing was pending.
+    ///
+    /// # Panics
+    /// Panics if composition fails with an error.
+    pub fn compose_once(&mut self) -> bool {
+        match self.composer.next() {
+            Some(Ok(())) => true,
+            Some(Err(error)) => panic!("Composition failed: {error}"),
+            None => false,
+        }
+    }
+
+    /// Compose all pending content and apply any queued updates.
+    ///
+    /// # Panics
+    /// Panics if composition fails with an error.
+    pub fn compose(&mut self) {
+        match self.composer.try_compose() {
+            Ok(()) | Err(TryComposeError::Pending) => {}
+            Err(TryComposeError::Error(error)) => panic!("Composition failed: {error}"),
+        }
+    }
+
+    /// Try to compose all pending content, returning any composition error.
+    pub fn try_compose(&mut self) -> Result<(), Box<dyn Error>> {
+        match self.composer.try_compose() {
+            Ok(()) | Err(TryComposeError::Pending) => Ok(()),
+            Err(TryComposeError::Error(error)) => Err(error),
+        }
+    }
+
+    /// Set the value of a [`use_mut`](crate::use_mut) hook, queueing its composable to be re-composed.
+    ///
+    /// # Panics
+    /// Panics if the hook doesn't exist, or isn't a `use_mut` hook of type `T`.
+    pub fn set<T: 'static>(&mut self, id: SignalId, value: T) {
+        self.composer.set_mut(&id.path, id.hook_idx, value);
+    }
+
+    /// Get the current composition tree (formatted like the [`Composer`]'s debug output).
+    pub fn tree(&self) -> String {
+        format!("{:?}", self.composer)
+    }
+
+    /// Assert that the current composition tree is equal to `expected`.
+    ///
+    /// # Panics
+    /// Panics if the tree is different.
+    #[track_caller]
+    pub fn assert_tree(&self, expected: &str) {
+        assert_eq!(self.tree(), expected);
+    }
+
+    /// Get a mutable reference to the underlying [`Composer`].
+    pub fn composer_mut(&mut self) -> &mut Composer {
+        &mut self.composer
+    }
+}
+
+impl fmt::Debug for TestHarness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("TestHarness").field(&self.composer).finish()
+    }
+}
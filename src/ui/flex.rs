@@ -0,0 +1,106 @@
+use crate::{
+    compose::Compose,
+    ecs::{spawn, Modifier, Modify},
+    Data, Scope, Signal,
+};
+use bevy_ui::prelude::*;
+use std::mem;
+
+/// Create a flex container that lays out its content horizontally.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Toolbar;
+///
+/// impl Compose for Toolbar {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         row(("Cut", "Copy", "Paste")).gap(Val::Px(8.))
+///     }
+/// }
+/// ```
+pub fn row<'a, C: Compose>(content: C) -> Flex<'a, C> {
+    Flex {
+        content,
+        flex_direction: FlexDirection::Row,
+        gap: Val::Px(0.),
+        modifier: Modifier::default(),
+    }
+}
+
+/// Create a flex container that lays out its content vertically.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Menu;
+///
+/// impl Compose for Menu {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         column(("New", "Open", "Save")).gap(Val::Px(4.))
+///     }
+/// }
+/// ```
+pub fn column<'a, C: Compose>(content: C) -> Flex<'a, C> {
+    Flex {
+        content,
+        flex_direction: FlexDirection::Column,
+        gap: Val::Px(0.),
+        modifier: Modifier::default(),
+    }
+}
+
+/// Flex container composable.
+///
+/// For more see [`row`] and [`column`].
+#[derive(Data)]
+#[actuate(path = "crate")]
+pub struct Flex<'a, C> {
+    content: C,
+    flex_direction: FlexDirection,
+    gap: Val,
+    modifier: Modifier<'a>,
+}
+
+impl<C> Flex<'_, C> {
+    /// Set the gap between each child along the main axis (default: 0px).
+    pub fn gap(mut self, gap: Val) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+impl<C: Compose> Compose for Flex<'_, C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let (row_gap, column_gap) = match cx.me().flex_direction {
+            FlexDirection::Row | FlexDirection::RowReverse => (Val::Px(0.), cx.me().gap),
+            FlexDirection::Column | FlexDirection::ColumnReverse => (cx.me().gap, Val::Px(0.)),
+        };
+
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(spawn(Node {
+                flex_direction: cx.me().flex_direction,
+                row_gap,
+                column_gap,
+                ..Default::default()
+            }))
+            .content(unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) })
+    }
+}
+
+impl<'a, C: Compose> Modify<'a> for Flex<'a, C> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
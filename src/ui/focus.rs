@@ -0,0 +1,177 @@
+use crate::{
+    ecs::Spawn, use_context, use_drop, use_memo_local, use_mut, use_recompose, use_ref, ScopeState,
+    Signal, SignalMut,
+};
+use bevy_ecs::prelude::*;
+use bevy_picking::prelude::*;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+/// Focus context for [`use_focus`].
+///
+/// This is provided to every [`Composition`](crate::ecs::Composition),
+/// or can be provided manually with [`use_provider`](crate::use_provider) to scope focus to a subtree.
+#[derive(Default)]
+pub struct FocusContext {
+    focused: RefCell<Option<Focused>>,
+    next_id: Cell<u64>,
+}
+
+/// Currently focused composable of a [`FocusContext`].
+struct Focused {
+    id: u64,
+    recompose: Rc<dyn Fn()>,
+}
+
+impl FocusContext {
+    /// Remove focus from the currently focused composable (if any).
+    pub fn blur(&self) {
+        if let Some(last) = self.focused.take() {
+            (last.recompose)();
+        }
+    }
+
+    fn is_focused(&self, id: u64) -> bool {
+        self.focused
+            .borrow()
+            .as_ref()
+            .map(|focused| focused.id == id)
+            .unwrap_or(false)
+    }
+
+    fn focus(&self, id: u64, recompose: Rc<dyn Fn()>) {
+        if self.is_focused(id) {
+            return;
+        }
+
+        let last = self.focused.borrow_mut().replace(Focused {
+            id,
+            recompose: recompose.clone(),
+        });
+        if let Some(last) = last {
+            (last.recompose)();
+        }
+
+        recompose();
+    }
+
+    fn remove(&self, id: u64) {
+        let mut focused = self.focused.borrow_mut();
+        if focused
+            .as_ref()
+            .map(|focused| focused.id == id)
+            .unwrap_or(false)
+        {
+            *focused = None;
+        }
+    }
+}
+
+/// Use the focus state of this composable.
+///
+/// Returns whether this composable is focused, and a function to request focus.
+/// Only one composable can be focused at a time for each [`FocusContext`],
+/// so requesting focus re-composes the previously focused composable (if any).
+///
+/// For click-to-focus, see [`use_focusable`].
+///
+/// # Panics
+/// Panics if a [`FocusContext`] was not provided by a parent composable.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Input;
+///
+/// impl Compose for Input {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let (is_focused, request_focus) = use_focus(&cx);
+///
+///         if use_is_initial(&cx) {
+///             request_focus();
+///         }
+///
+///         text::label(if *is_focused { "Focused" } else { "Not focused" })
+///     }
+/// }
+/// ```
+pub fn use_focus(cx: ScopeState<'_>) -> (Signal<'_, bool>, Rc<dyn Fn()>) {
+    let focus_cx = use_context::<FocusContext>(cx).expect("`use_focus` requires a `FocusContext`.");
+
+    let id = *use_ref(cx, || {
+        let id = focus_cx.next_id.get();
+        focus_cx.next_id.set(id + 1);
+        id
+    });
+
+    let recompose = use_recompose(cx);
+    let request_focus = use_ref(cx, || {
+        let focus_cx = focus_cx.clone();
+        Rc::new(move || focus_cx.focus(id, recompose.clone())) as Rc<dyn Fn()>
+    })
+    .clone();
+
+    use_drop(cx, move || focus_cx.remove(id));
+
+    let is_focused = focus_cx.is_focused(id);
+    let is_focused = use_memo_local(cx, is_focused, || is_focused);
+
+    (
+        Signal {
+            value: is_focused,
+            generation: &cx.generation as _,
+        },
+        request_focus,
+    )
+}
+
+/// Use the focus state of a spawned node that is focused when clicked.
+///
+/// Returns whether the node is focused, and a function that adds the click observer to a [`Spawn`].
+/// This function can be applied directly, or with [`Modify::modify`](crate::ecs::Modify::modify).
+///
+/// For more see [`use_focus`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Input;
+///
+/// impl Compose for Input {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let (is_focused, focusable) = use_focusable(&cx);
+///
+///         let color = if *is_focused { Color::WHITE } else { Color::BLACK };
+///         focusable(spawn((Node::default(), BackgroundColor(color))))
+///     }
+/// }
+/// ```
+pub fn use_focusable<'a>(
+    cx: ScopeState<'a>,
+) -> (
+    Signal<'a, bool>,
+    impl Fn(Spawn<'a>) -> Spawn<'a> + Copy + 'a,
+) {
+    let (is_focused, request_focus) = use_focus(cx);
+
+    // Clicks are counted by observers, and focus is requested when this scope is re-composed.
+    let clicks = use_mut(cx, || 0u64);
+    let last_clicks = use_ref(cx, || Cell::new(0));
+    if last_clicks.replace(*clicks) != *clicks {
+        request_focus();
+    }
+
+    (is_focused, move |spawn: Spawn<'a>| {
+        spawn.observe(move |_: Trigger<Pointer<Click>>| SignalMut::update(clicks, |x| *x += 1))
+    })
+}
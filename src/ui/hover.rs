@@ -0,0 +1,42 @@
+use crate::{ecs::Spawn, use_mut, ScopeState, Signal, SignalMut};
+use bevy_ecs::prelude::*;
+use bevy_picking::prelude::*;
+
+/// Use the hover state of a spawned node.
+///
+/// Returns whether the node is hovered, and a function that adds the hover observers to a [`Spawn`].
+/// This function can be applied directly, or with [`Modify::modify`](crate::ecs::Modify::modify).
+/// This scope is only re-composed when the node is entered or left.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Card;
+///
+/// impl Compose for Card {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let (is_hovered, hover) = use_hover(&cx);
+///
+///         let color = if *is_hovered { Color::WHITE } else { Color::BLACK };
+///         hover(spawn((Node::default(), BackgroundColor(color))))
+///     }
+/// }
+/// ```
+pub fn use_hover<'a>(
+    cx: ScopeState<'a>,
+) -> (
+    Signal<'a, bool>,
+    impl Fn(Spawn<'a>) -> Spawn<'a> + Copy + 'a,
+) {
+    let is_hovered = use_mut(cx, || false);
+
+    (SignalMut::as_ref(is_hovered), move |spawn: Spawn<'a>| {
+        spawn
+            .observe(move |_: Trigger<Pointer<Over>>| SignalMut::set_if_neq(is_hovered, true))
+            .observe(move |_: Trigger<Pointer<Out>>| SignalMut::set_if_neq(is_hovered, false))
+    })
+}
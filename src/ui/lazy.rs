@@ -0,0 +1,167 @@
+use crate::{
+    compose::Compose,
+    data::Data,
+    ecs::{spawn, use_world, Modifier, Modify},
+    use_context, use_mut, Scope, SignalMut,
+};
+use bevy_ecs::prelude::*;
+use bevy_math::{Rect, Vec2};
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{prelude::*, ComputedNode};
+use std::{cell::Cell, mem, rc::Rc};
+
+/// Visibility context for [`lazy`] composables.
+///
+/// This is provided by [`scroll_view`](super::scroll_view),
+/// or can be provided manually with [`use_provider`](crate::use_provider).
+#[derive(Default)]
+pub struct VisibilityContext {
+    viewport: Cell<Option<Entity>>,
+}
+
+impl VisibilityContext {
+    /// Create a new visibility context for content inside the `viewport` node.
+    pub fn new(viewport: Entity) -> Self {
+        Self {
+            viewport: Cell::new(Some(viewport)),
+        }
+    }
+
+    /// Get the node that content must overlap to be visible.
+    pub fn viewport(&self) -> Option<Entity> {
+        self.viewport.get()
+    }
+
+    /// Set the node that content must overlap to be visible.
+    pub fn set_viewport(&self, viewport: Option<Entity>) {
+        self.viewport.set(viewport);
+    }
+}
+
+/// Create a composable that defers composing its content until it's visible.
+///
+/// A placeholder node is spawned in place of the content, so the content keeps its position among its siblings.
+/// Once the placeholder overlaps the viewport of the nearest [`VisibilityContext`],
+/// `make_content` is called and the content is composed into the placeholder.
+/// Without a [`VisibilityContext`], the content is always visible.
+///
+/// By default, content stays composed after it becomes hidden (see [`Lazy::unmount_hidden`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Rows;
+///
+/// impl Compose for Rows {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         scroll_view(compose::from_iter(0..1000, |idx| {
+///             let idx = *idx;
+///             lazy(move || format!("Row {idx}"))
+///                 .placeholder_size(Vec2::new(0., 30.))
+///                 .unmount_hidden(true)
+///         }))
+///     }
+/// }
+/// ```
+pub fn lazy<'a, C: Compose>(make_content: impl Fn() -> C + 'a) -> Lazy<'a, C> {
+    Lazy {
+        make_content: Rc::new(make_content),
+        placeholder_size: Vec2::ZERO,
+        is_unmounted_when_hidden: false,
+        modifier: Modifier::default(),
+    }
+}
+
+/// Lazy composable.
+///
+/// For more see [`lazy`].
+#[derive(Data)]
+#[actuate(path = "crate")]
+pub struct Lazy<'a, C> {
+    make_content: Rc<dyn Fn() -> C + 'a>,
+    placeholder_size: Vec2,
+    is_unmounted_when_hidden: bool,
+    modifier: Modifier<'a>,
+}
+
+impl<C> Lazy<'_, C> {
+    /// Set the minimum size of the placeholder node, in logical pixels (default: zero).
+    ///
+    /// This should be close to the size of the content, so hidden content takes up space.
+    pub fn placeholder_size(mut self, size: Vec2) -> Self {
+        self.placeholder_size = size;
+        self
+    }
+
+    /// Set whether to drop the content (and its state) when it's hidden again (default: false).
+    pub fn unmount_hidden(mut self, is_unmounted: bool) -> Self {
+        self.is_unmounted_when_hidden = is_unmounted;
+        self
+    }
+}
+
+impl<C: Compose> Compose for Lazy<'_, C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let visibility_cx = use_context::<VisibilityContext>(&cx).ok().cloned();
+        let is_visible = use_mut(&cx, || visibility_cx.is_none());
+        let entity_cell = use_mut(&cx, || None);
+
+        use_world(
+            &cx,
+            move |node_query: Query<(&ComputedNode, &GlobalTransform)>| {
+                let Some(viewport) = visibility_cx.as_ref().and_then(|cx| cx.viewport()) else {
+                    SignalMut::set_if_neq(is_visible, true);
+                    return;
+                };
+
+                let Some(entity) = *entity_cell else {
+                    return;
+                };
+
+                let rect = |entity| {
+                    node_query.get(entity).ok().map(|(node, transform)| {
+                        Rect::from_center_size(transform.translation().truncate(), node.size())
+                    })
+                };
+                let (Some(node_rect), Some(viewport_rect)) = (rect(entity), rect(viewport)) else {
+                    return;
+                };
+
+                let is_overlapping = !node_rect.intersect(viewport_rect).is_empty();
+                if is_overlapping || cx.me().is_unmounted_when_hidden {
+                    SignalMut::set_if_neq(is_visible, is_overlapping);
+                }
+            },
+        );
+
+        let content = if *is_visible {
+            Some((cx.me().make_content)())
+        } else {
+            None
+        };
+
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(
+                spawn(Node {
+                    min_width: Val::Px(cx.me().placeholder_size.x),
+                    min_height: Val::Px(cx.me().placeholder_size.y),
+                    ..Default::default()
+                })
+                .on_spawn(move |entity| SignalMut::set(entity_cell, Some(entity.id()))),
+            )
+            .content(content)
+    }
+}
+
+impl<'a, C: Compose> Modify<'a> for Lazy<'a, C> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
@@ -1,4 +1,4 @@
-use super::Theme;
+use super::{Spacing, Theme};
 use crate::{
     compose::Compose,
     ecs::spawn,
@@ -19,6 +19,8 @@ pub fn container<'a, C>(content: C) -> Container<'a, C> {
         padding: UiRect::all(Val::Px(12.))
             .with_left(Val::Px(24.))
             .with_right(Val::Px(24.)),
+        content_padding: None,
+        gap: Spacing::None,
         background_color: None,
         border_radius: BorderRadius::all(Val::Px(12.)),
         modifier: Modifier::default(),
@@ -33,6 +35,8 @@ pub fn container<'a, C>(content: C) -> Container<'a, C> {
 pub struct Container<'a, C> {
     content: C,
     padding: UiRect,
+    content_padding: Option<Spacing>,
+    gap: Spacing,
     elevation: f32,
     modifier: Modifier<'a>,
     background_color: Option<Color>,
@@ -53,6 +57,8 @@ impl<'a, C> Container<'a, C> {
     }
 
     /// Set the elevation of this button.
+    ///
+    /// Without a custom background color, this will also tint the surface container color (see [`Theme::surface_container_at`]).
     pub fn elevation(mut self, elevation: f32) -> Self {
         self.elevation = elevation;
         self
@@ -61,6 +67,24 @@ impl<'a, C> Container<'a, C> {
     /// Set the padding of this button.
     pub fn padding(mut self, padding: UiRect) -> Self {
         self.padding = padding;
+        self.content_padding = None;
+        self
+    }
+
+    /// Set the padding of this container's content from a [`Spacing`] token.
+    ///
+    /// This is scaled by the current [`Theme`] (see [`Theme::spacing`]).
+    pub fn content_padding(mut self, spacing: Spacing) -> Self {
+        self.content_padding = Some(spacing);
+        self
+    }
+
+    /// Set the gap between this container's children from a [`Spacing`] token.
+    ///
+    /// This sets both the row and column gap.
+    /// This is scaled by the current [`Theme`] (see [`Theme::spacing`]).
+    pub fn content_gap(mut self, spacing: Spacing) -> Self {
+        self.gap = spacing;
         self
     }
 }
@@ -76,7 +100,13 @@ impl<C: Compose> Compose for Container<'_, C> {
                     flex_direction: FlexDirection::Column,
                     justify_content: JustifyContent::Center,
                     align_items: AlignItems::Center,
-                    padding: cx.me().padding,
+                    padding: cx
+                        .me()
+                        .content_padding
+                        .map(|spacing| UiRect::all(theme.spacing(spacing)))
+                        .unwrap_or(cx.me().padding),
+                    row_gap: theme.spacing(cx.me().gap),
+                    column_gap: theme.spacing(cx.me().gap),
                     overflow: Overflow::clip(),
                     ..Default::default()
                 },
@@ -84,7 +114,7 @@ impl<C: Compose> Compose for Container<'_, C> {
                 BackgroundColor(
                     cx.me()
                         .background_color
-                        .unwrap_or(theme.colors.surface_container),
+                        .unwrap_or_else(|| theme.surface_container_at(cx.me().elevation)),
                 ),
                 BoxShadow {
                     color: Color::srgba(0., 0., 0., 0.12 * cx.me().elevation),
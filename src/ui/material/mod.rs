@@ -1,4 +1,5 @@
-use bevy_color::Color;
+use bevy_color::{Color, Mix};
+use bevy_ui::Val;
 use std::ops::Index;
 
 mod button;
@@ -7,11 +8,20 @@ pub use self::button::{button, Button};
 mod container;
 pub use self::container::{container, Container};
 
+#[cfg(feature = "animation")]
+mod progress;
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+pub use self::progress::{circular_progress, linear_progress, CircularProgress, LinearProgress};
+
 mod radio;
-pub use self::radio::{radio_button, RadioButton};
+pub use self::radio::{
+    radio_button, radio_group, radio_group_button, RadioButton, RadioGroup, RadioGroupButton,
+    RadioGroupContext,
+};
 
 mod ui;
-pub use self::ui::{material_ui, MaterialUi};
+pub use self::ui::{material_ui, Density, MaterialUi};
 
 /// Text composables.
 pub mod text;
@@ -45,6 +55,13 @@ pub struct TypographyStyle {
     pub line_height: f32,
 }
 
+impl TypographyStyle {
+    fn scale(&mut self, scale_factor: f32) {
+        self.font_size *= scale_factor;
+        self.line_height *= scale_factor;
+    }
+}
+
 /// Typography style kind.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TypographyStyleKind {
@@ -83,6 +100,14 @@ impl Index<TypographyStyleKind> for TypographyToken {
     }
 }
 
+impl TypographyToken {
+    fn scale(&mut self, scale_factor: f32) {
+        self.small.scale(scale_factor);
+        self.medium.scale(scale_factor);
+        self.large.scale(scale_factor);
+    }
+}
+
 /// Typography kind.
 #[derive(Clone, Copy)]
 pub enum TypographyKind {
@@ -128,6 +153,48 @@ impl Index<TypographyKind> for Typography {
     }
 }
 
+/// Spacing design token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spacing {
+    /// No spacing (0dp).
+    None,
+
+    /// Extra small spacing (4dp).
+    ExtraSmall,
+
+    /// Small spacing (8dp).
+    Small,
+
+    /// Medium spacing (16dp).
+    Medium,
+
+    /// Large spacing (24dp).
+    Large,
+
+    /// Extra large spacing (32dp).
+    ExtraLarge,
+}
+
+impl Spacing {
+    /// Get the size of this spacing in logical pixels.
+    pub fn px(self) -> f32 {
+        match self {
+            Spacing::None => 0.,
+            Spacing::ExtraSmall => 4.,
+            Spacing::Small => 8.,
+            Spacing::Medium => 16.,
+            Spacing::Large => 24.,
+            Spacing::ExtraLarge => 32.,
+        }
+    }
+}
+
+impl From<Spacing> for Val {
+    fn from(spacing: Spacing) -> Self {
+        Val::Px(spacing.px())
+    }
+}
+
 /// Material UI theme.
 #[derive(Clone, PartialEq)]
 pub struct Theme {
@@ -136,6 +203,46 @@ pub struct Theme {
 
     /// Theme typography.
     pub typography: Typography,
+
+    /// Scale factor for [`Spacing`] tokens (see [`Theme::spacing`]).
+    pub spacing_scale: f32,
+}
+
+impl Theme {
+    /// Get the size of a [`Spacing`] token, scaled by this theme's [`spacing_scale`](Theme::spacing_scale).
+    pub fn spacing(&self, spacing: Spacing) -> Val {
+        Val::Px(spacing.px() * self.spacing_scale)
+    }
+
+    /// Create a copy of this theme with typography scaled by `scale_factor`,
+    /// and spacing scaled by both `scale_factor` and `density`.
+    pub fn scaled(&self, scale_factor: f32, density: Density) -> Self {
+        let mut theme = self.clone();
+
+        for token in [
+            &mut theme.typography.body,
+            &mut theme.typography.headline,
+            &mut theme.typography.label,
+            &mut theme.typography.title,
+        ] {
+            token.scale(scale_factor);
+        }
+
+        theme.spacing_scale *= scale_factor * density.scale();
+        theme
+    }
+
+    /// Get the surface container color for an elevation level (from 0 to 5).
+    ///
+    /// Higher elevations are tinted towards the primary color.
+    pub fn surface_container_at(&self, elevation: f32) -> Color {
+        const TINTS: [f32; 6] = [0., 0.05, 0.08, 0.11, 0.12, 0.14];
+
+        let level = (elevation.round().max(0.) as usize).min(TINTS.len() - 1);
+        self.colors
+            .surface_container
+            .mix(&self.colors.primary, TINTS[level])
+    }
 }
 
 impl Default for Theme {
@@ -217,6 +324,7 @@ impl Default for Theme {
                     },
                 },
             },
+            spacing_scale: 1.,
         }
     }
 }
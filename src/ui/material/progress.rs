@@ -0,0 +1,270 @@
+use super::Theme;
+use crate::{
+    compose::{self, Compose},
+    ecs::{spawn, use_world_value, Modifier, Modify},
+    use_context, Data, Scope, ScopeState, Signal,
+};
+use bevy_color::{Alpha, Color};
+use bevy_ecs::system::Res;
+use bevy_time::Time;
+use bevy_ui::{BackgroundColor, BorderRadius, Node, Overflow, PositionType, Val};
+use std::{f32::consts::TAU, mem};
+
+/// Duration of one indeterminate sweep, in seconds.
+const SWEEP_PERIOD: f32 = 1.5;
+
+/// Number of segments in a [`CircularProgress`].
+const CIRCULAR_SEGMENTS: usize = 24;
+
+/// Use the current phase (from 0 to 1) of an indeterminate sweep.
+///
+/// This only re-composes the scope on each frame while `is_indeterminate` is `true`.
+fn use_sweep(cx: ScopeState<'_>, is_indeterminate: bool) -> Signal<'_, f32> {
+    use_world_value(cx, move |time: Res<Time>| {
+        if is_indeterminate {
+            (time.elapsed_secs() % SWEEP_PERIOD) / SWEEP_PERIOD
+        } else {
+            0.
+        }
+    })
+}
+
+/// Create a material UI linear progress indicator.
+///
+/// A value of `Some` shows determinate progress (clamped from 0 to 1),
+/// and `None` shows an animated indeterminate sweep.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Download {
+///     progress: Option<f32>,
+/// }
+///
+/// impl Compose for Download {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         linear_progress(cx.me().progress)
+///     }
+/// }
+/// ```
+pub fn linear_progress<'a>(value: Option<f32>) -> LinearProgress<'a> {
+    LinearProgress {
+        progress: value,
+        color: None,
+        height: Val::Px(4.),
+        modifier: Modifier::default(),
+    }
+}
+
+/// Material UI linear progress indicator.
+///
+/// For more see [`linear_progress`].
+#[derive(Clone, Debug, Data)]
+#[actuate(path = "crate")]
+pub struct LinearProgress<'a> {
+    progress: Option<f32>,
+    color: Option<Color>,
+    height: Val,
+    modifier: Modifier<'a>,
+}
+
+impl LinearProgress<'_> {
+    /// Set the color of this indicator (default: the theme's primary color).
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the height of this indicator (default: 4px).
+    pub fn height(mut self, height: Val) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl Compose for LinearProgress<'_> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let theme = use_context::<Theme>(&cx).cloned().unwrap_or_default();
+        let color = cx.me().color.unwrap_or(theme.colors.primary);
+
+        let phase = use_sweep(&cx, cx.me().progress.is_none());
+
+        let (start, len) = match cx.me().progress {
+            Some(value) => (0., value.clamp(0., 1.)),
+            // Sweep a bar across the track, starting and ending off-screen.
+            None => (*phase * 1.4 - 0.4, 0.4),
+        };
+
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(spawn((
+                Node {
+                    width: Val::Percent(100.),
+                    height: cx.me().height,
+                    overflow: Overflow::clip(),
+                    ..Default::default()
+                },
+                BackgroundColor(color.with_alpha(0.24)),
+                BorderRadius::MAX,
+            )))
+            .content(spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(start * 100.),
+                    width: Val::Percent(len * 100.),
+                    height: Val::Percent(100.),
+                    ..Default::default()
+                },
+                BackgroundColor(color),
+                BorderRadius::MAX,
+            )))
+    }
+}
+
+impl<'a> Modify<'a> for LinearProgress<'a> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
+
+/// Create a material UI circular progress indicator.
+///
+/// A value of `Some` shows determinate progress (clamped from 0 to 1),
+/// and `None` shows an animated indeterminate sweep.
+/// The ring is drawn as segments, filled clockwise from the top.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Loading;
+///
+/// impl Compose for Loading {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         circular_progress(None)
+///     }
+/// }
+/// ```
+pub fn circular_progress<'a>(value: Option<f32>) -> CircularProgress<'a> {
+    CircularProgress {
+        progress: value,
+        color: None,
+        size: 40.,
+        modifier: Modifier::default(),
+    }
+}
+
+/// Material UI circular progress indicator.
+///
+/// For more see [`circular_progress`].
+#[derive(Clone, Debug, Data)]
+#[actuate(path = "crate")]
+pub struct CircularProgress<'a> {
+    progress: Option<f32>,
+    color: Option<Color>,
+    size: f32,
+    modifier: Modifier<'a>,
+}
+
+impl CircularProgress<'_> {
+    /// Set the color of this indicator (default: the theme's primary color).
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the size of this indicator in logical pixels (default: 40).
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Data)]
+#[actuate(path = "crate")]
+struct Segment {
+    left: f32,
+    top: f32,
+    is_filled: bool,
+}
+
+impl Compose for CircularProgress<'_> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let theme = use_context::<Theme>(&cx).cloned().unwrap_or_default();
+        let color = cx.me().color.unwrap_or(theme.colors.primary);
+        let track_color = color.with_alpha(0.24);
+
+        let phase = use_sweep(&cx, cx.me().progress.is_none());
+
+        let (start, len) = match cx.me().progress {
+            Some(value) => (
+                0,
+                (value.clamp(0., 1.) * CIRCULAR_SEGMENTS as f32).round() as usize,
+            ),
+            // Rotate a third of the ring around the track.
+            None => (
+                (*phase * CIRCULAR_SEGMENTS as f32) as usize,
+                CIRCULAR_SEGMENTS / 3,
+            ),
+        };
+
+        let size = cx.me().size;
+        let dot_size = size / 10.;
+        let radius = (size - dot_size) / 2.;
+
+        let segments: Vec<Segment> = (0..CIRCULAR_SEGMENTS)
+            .map(|idx| {
+                let angle = idx as f32 / CIRCULAR_SEGMENTS as f32 * TAU - TAU / 4.;
+
+                Segment {
+                    left: radius + radius * angle.cos(),
+                    top: radius + radius * angle.sin(),
+                    is_filled: (idx + CIRCULAR_SEGMENTS - start) % CIRCULAR_SEGMENTS < len,
+                }
+            })
+            .collect();
+
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(spawn(Node {
+                width: Val::Px(size),
+                height: Val::Px(size),
+                ..Default::default()
+            }))
+            .content(compose::from_iter(segments, move |segment| {
+                let Segment {
+                    left,
+                    top,
+                    is_filled,
+                } = *segment;
+
+                spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(left),
+                        top: Val::Px(top),
+                        width: Val::Px(dot_size),
+                        height: Val::Px(dot_size),
+                        ..Default::default()
+                    },
+                    BackgroundColor(if is_filled { color } else { track_color }),
+                    BorderRadius::MAX,
+                ))
+            }))
+    }
+}
+
+impl<'a> Modify<'a> for CircularProgress<'a> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
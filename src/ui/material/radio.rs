@@ -3,10 +3,11 @@ use crate::{
     compose::Compose,
     ecs::spawn,
     ecs::{Modifier, Modify},
-    use_context, Data, Scope,
+    use_context, use_drop, use_mut, use_provider, Data, Scope, Signal, SignalMut,
 };
 use bevy_color::Color;
 use bevy_ui::{BackgroundColor, BorderColor, BorderRadius, BoxShadow, Node, UiRect, Val};
+use std::{cell::RefCell, mem};
 
 /// Create a material UI radio button.
 pub fn radio_button<'a>() -> RadioButton<'a> {
@@ -115,3 +116,223 @@ impl<'a> Modify<'a> for RadioButton<'a> {
         &mut self.modifier
     }
 }
+
+/// Create a material UI radio group.
+///
+/// This provides a [`RadioGroupContext`] to its content,
+/// allowing a single [`RadioGroupButton`] to be selected at a time.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Example;
+///
+/// impl Compose for Example {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let selected = use_mut(&cx, || 0);
+///
+///         radio_group(
+///             selected,
+///             (
+///                 radio_group_button(0),
+///                 radio_group_button(1),
+///                 radio_group_button(2),
+///             ),
+///         )
+///     }
+/// }
+/// ```
+pub fn radio_group<'a, K, C>(selected: SignalMut<'a, K>, content: C) -> RadioGroup<'a, K, C>
+where
+    K: Clone + PartialEq + Send + 'static,
+    C: Compose,
+{
+    RadioGroup { selected, content }
+}
+
+/// Material UI radio group.
+///
+/// For more see [`radio_group`].
+#[derive(Data)]
+#[actuate(path = "crate")]
+pub struct RadioGroup<'a, K, C> {
+    selected: SignalMut<'a, K>,
+    content: C,
+}
+
+impl<K, C> Compose for RadioGroup<'_, K, C>
+where
+    K: Data + Clone + PartialEq + Send + 'static,
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        use_provider(&cx, || RadioGroupContext {
+            // Safety: This context is only available to the content of this group,
+            // which cannot outlive the `selected` signal.
+            selected: unsafe {
+                mem::transmute::<SignalMut<'_, K>, SignalMut<'static, K>>(cx.me().selected)
+            },
+            buttons: RefCell::new(Vec::new()),
+        });
+
+        // Safety: The content of this group is borrowed from `cx.me()`.
+        unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
+    }
+}
+
+/// Radio group context.
+///
+/// This is provided by [`radio_group`].
+pub struct RadioGroupContext<K: 'static> {
+    selected: SignalMut<'static, K>,
+    buttons: RefCell<Vec<(K, SignalMut<'static, ()>)>>,
+}
+
+impl<K> RadioGroupContext<K>
+where
+    K: Clone + PartialEq + Send + 'static,
+{
+    /// Get the currently selected value.
+    pub fn selected(&self) -> &K {
+        &self.selected
+    }
+
+    /// Select `value`, re-composing only the previously- and newly-selected buttons.
+    ///
+    /// The group's `selected` signal is updated without re-composing its owner.
+    pub fn select(&self, value: K) {
+        let last = &*self.selected;
+        if *last == value {
+            return;
+        }
+
+        for (button_value, updater) in &*self.buttons.borrow() {
+            if button_value == last || *button_value == value {
+                SignalMut::set(*updater, ());
+            }
+        }
+
+        SignalMut::with(self.selected, move |selected| *selected = value);
+    }
+}
+
+/// Create a material UI radio button inside of a [`radio_group`].
+///
+/// # Panics
+/// Composing this button will panic if it is not inside of a [`radio_group`] with the same value type.
+pub fn radio_group_button<'a, K>(value: K) -> RadioGroupButton<'a, K>
+where
+    K: Data + Clone + PartialEq + Send + Sync + 'static,
+{
+    RadioGroupButton {
+        key: value,
+        button: radio_button(),
+    }
+}
+
+/// Material UI radio button inside of a [`radio_group`].
+///
+/// For more see [`radio_group_button`].
+#[derive(Clone, Data)]
+#[actuate(path = "crate")]
+pub struct RadioGroupButton<'a, K> {
+    key: K,
+    button: RadioButton<'a>,
+}
+
+impl<'a, K> RadioGroupButton<'a, K> {
+    /// Set the inner radius of this radio button.
+    pub fn inner_radius(mut self, inner_radius: f32) -> Self {
+        self.button.inner_radius = inner_radius;
+        self
+    }
+
+    /// Set the outer radius of this radio button.
+    pub fn outer_radius(mut self, outer_radius: f32) -> Self {
+        self.button.outer_radius = outer_radius;
+        self
+    }
+
+    /// Set the border width of this radio button.
+    pub fn border_width(mut self, border_width: f32) -> Self {
+        self.button.border_width = border_width;
+        self
+    }
+
+    /// Set the elevation of this radio button.
+    pub fn elevation(mut self, elevation: f32) -> Self {
+        self.button.elevation = elevation;
+        self
+    }
+}
+
+/// Pointer to a [`RadioGroupContext`] for click handlers.
+struct RadioGroupPtr<K: 'static>(*const RadioGroupContext<K>);
+
+impl<K> Clone for RadioGroupPtr<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for RadioGroupPtr<K> {}
+
+// Safety: Click handlers are only run on the thread of the composition.
+unsafe impl<K> Send for RadioGroupPtr<K> {}
+
+unsafe impl<K> Sync for RadioGroupPtr<K> {}
+
+impl<K> Compose for RadioGroupButton<'_, K>
+where
+    K: Data + Clone + PartialEq + Send + Sync + 'static,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let group = use_context::<RadioGroupContext<K>>(&cx)
+            .expect("`radio_group_button` must be inside of a `radio_group`.");
+
+        // Register this button with the group to re-compose it when its selection changes.
+        let updater = use_mut(&cx, || ());
+        {
+            let mut buttons = group.buttons.borrow_mut();
+            if let Some((value, _)) = buttons.iter_mut().find(|(_, x)| x.ptr == updater.ptr) {
+                *value = cx.me().key.clone();
+            } else {
+                // Safety: This button is removed from the group when dropped.
+                let updater =
+                    unsafe { mem::transmute::<SignalMut<'_, ()>, SignalMut<'static, ()>>(updater) };
+                buttons.push((cx.me().key.clone(), updater));
+            }
+        }
+
+        use_drop(&cx, move || {
+            group
+                .buttons
+                .borrow_mut()
+                .retain(|(_, x)| x.ptr != updater.ptr);
+        });
+
+        let is_selected = *group.selected == cx.me().key;
+
+        let group_ptr = RadioGroupPtr(&**group);
+        let value = cx.me().key.clone();
+
+        cx.me()
+            .button
+            .clone()
+            .is_enabled(is_selected)
+            .on_click(move || {
+                // Safety: The group context outlives this button.
+                let group_ptr = group_ptr;
+                unsafe { &*group_ptr.0 }.select(value.clone())
+            })
+    }
+}
+
+impl<'a, K> Modify<'a> for RadioGroupButton<'a, K> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.button.modifier
+    }
+}
@@ -1,12 +1,16 @@
 use super::{Theme, TypographyKind, TypographyStyleKind};
 use crate::{
-    ecs::{spawn, Modifier, Modify},
+    ecs::{spawn, use_world, Modifier, Modify},
     prelude::Compose,
-    use_context,
+    use_cell, use_context, use_mut, SignalMut,
 };
 use actuate_macros::Data;
-use bevy_text::{TextColor, TextFont};
-use bevy_ui::prelude::Text as UiText;
+use bevy_ecs::{entity::Entity, system::Query};
+use bevy_hierarchy::Parent;
+use bevy_math::Vec2;
+use bevy_text::{TextColor, TextFont, TextLayoutInfo};
+use bevy_ui::{prelude::Text as UiText, ComputedNode};
+use std::{cell::Cell, rc::Rc};
 
 /// Create a material UI text body.
 pub fn body<'a>(content: impl Into<String>) -> Text<'a> {
@@ -35,6 +39,9 @@ pub fn text<'a>(content: impl Into<String>) -> Text<'a> {
         modifier: Modifier::default(),
         typography: TypographyKind::Label,
         typography_style: TypographyStyleKind::Medium,
+        on_measure: Rc::new(|_| {}),
+        max_lines: None,
+        is_ellipsis: false,
     }
 }
 
@@ -46,9 +53,12 @@ pub struct Text<'a> {
     typography: TypographyKind,
     typography_style: TypographyStyleKind,
     modifier: Modifier<'a>,
+    on_measure: Rc<dyn Fn(Vec2) + 'a>,
+    max_lines: Option<usize>,
+    is_ellipsis: bool,
 }
 
-impl Text<'_> {
+impl<'a> Text<'a> {
     /// Set the typography of this text.
     pub fn typography(mut self, typography: TypographyKind) -> Self {
         self.typography = typography;
@@ -60,6 +70,91 @@ impl Text<'_> {
         self.typography_style = typography_style;
         self
     }
+
+    /// Set a function to call with the measured (logical) size of this text after layout.
+    ///
+    /// This is called after the first layout, and again whenever the measured size changes.
+    pub fn on_measure(mut self, f: impl Fn(Vec2) + 'a) -> Self {
+        self.on_measure = Rc::new(f);
+        self
+    }
+
+    /// Set the maximum number of lines to show, truncating any overflowing text.
+    ///
+    /// Truncation is measured from the text's layout,
+    /// and recomputed when the content or the width of its parent changes.
+    /// Glyphs are mapped back to characters, so this assumes one glyph per character (e.g. no ligatures).
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Set whether truncated text ends with an ellipsis (default: `false`).
+    ///
+    /// For more see [`Text::max_lines`].
+    pub fn ellipsis(mut self, is_ellipsis: bool) -> Self {
+        self.is_ellipsis = is_ellipsis;
+        self
+    }
+}
+
+/// Truncated length of some text content.
+#[derive(Clone, PartialEq)]
+struct Truncation {
+    /// Content this truncation was measured for.
+    content: String,
+
+    /// Number of characters to show (excluding newlines).
+    len: usize,
+
+    /// Width of the parent node this truncation was measured for.
+    width: f32,
+}
+
+/// Count the characters laid out in the first `max_lines` lines,
+/// or return `None` if the layout fits within `max_lines`.
+fn truncated_len(layout: &TextLayoutInfo, max_lines: usize) -> Option<usize> {
+    let mut lines = 0;
+    let mut last_x = f32::INFINITY;
+
+    for (idx, glyph) in layout.glyphs.iter().enumerate() {
+        // Each line starts back at the left edge.
+        if glyph.position.x < last_x {
+            lines += 1;
+            if lines > max_lines {
+                return Some(idx);
+            }
+        }
+        last_x = glyph.position.x;
+    }
+
+    None
+}
+
+/// Truncate `content` to its first `len` characters (excluding newlines).
+fn truncate(content: &str, len: usize, is_ellipsis: bool) -> String {
+    let mut count = 0;
+    let end = content
+        .char_indices()
+        .find(|(_, c)| {
+            if count == len {
+                return true;
+            }
+            if *c != '\n' {
+                count += 1;
+            }
+            false
+        })
+        .map(|(idx, _)| idx)
+        .unwrap_or(content.len());
+
+    let mut truncated = content[..end].trim_end().to_string();
+    if is_ellipsis {
+        // Replace the last character to make room for the ellipsis.
+        truncated.pop();
+        truncated.push('…');
+    }
+    truncated
 }
 
 impl Compose for Text<'_> {
@@ -68,14 +163,85 @@ impl Compose for Text<'_> {
 
         let style = &theme.typography[cx.me().typography][cx.me().typography_style];
 
+        let entity: &Cell<Option<Entity>> = use_cell(&cx, || None);
+        let last_size: &Cell<Option<Vec2>> = use_cell(&cx, || None);
+
+        let truncation: SignalMut<Option<Truncation>> = use_mut(&cx, || None);
+
+        use_world(
+            &cx,
+            move |nodes: Query<&ComputedNode>,
+                  layouts: Query<(&TextLayoutInfo, Option<&Parent>)>| {
+                let Some((entity, node)) = entity
+                    .get()
+                    .and_then(|entity| nodes.get(entity).ok().map(|node| (entity, node)))
+                else {
+                    return;
+                };
+
+                let size = node.size() * node.inverse_scale_factor();
+                if last_size.get() != Some(size) {
+                    last_size.set(Some(size));
+                    (cx.me().on_measure)(size);
+                }
+
+                let Some(max_lines) = cx.me().max_lines else {
+                    if truncation.is_some() {
+                        SignalMut::set(truncation, None);
+                    }
+                    return;
+                };
+
+                let Ok((layout, parent)) = layouts.get(entity) else {
+                    return;
+                };
+
+                // Measure against the parent's width, as the width of this node depends on its (truncated) content.
+                let width = parent
+                    .and_then(|parent| nodes.get(parent.get()).ok())
+                    .unwrap_or(node)
+                    .size()
+                    .x;
+
+                match &*truncation {
+                    Some(last) => {
+                        // Show the full content again to re-measure it.
+                        if last.width != width || last.content != cx.me().content {
+                            SignalMut::set(truncation, None);
+                        }
+                    }
+                    None => {
+                        if let Some(len) = truncated_len(layout, max_lines) {
+                            SignalMut::set(
+                                truncation,
+                                Some(Truncation {
+                                    content: cx.me().content.clone(),
+                                    len,
+                                    width,
+                                }),
+                            );
+                        }
+                    }
+                }
+            },
+        );
+
+        let content = match &*truncation {
+            Some(truncation) if truncation.content == cx.me().content => {
+                truncate(&cx.me().content, truncation.len, cx.me().is_ellipsis)
+            }
+            _ => cx.me().content.clone(),
+        };
+
         spawn((
-            UiText::new(cx.me().content.clone()),
+            UiText::new(content),
             TextColor(theme.colors.text),
             TextFont {
                 font_size: style.font_size,
                 ..Default::default()
             },
         ))
+        .on_spawn(move |entity_mut| entity.set(Some(entity_mut.id())))
     }
 }
 
@@ -1,23 +1,48 @@
 use super::Theme;
 use crate::{
-    ecs::{spawn, Modifier, Modify},
+    ecs::{shortcuts, spawn, ModalContext, Modifier, Modify},
     prelude::Compose,
-    use_provider, Scope, Signal,
+    use_provider, use_provider_memo, Scope, Signal,
 };
 use actuate_macros::Data;
 use bevy_ui::{BackgroundColor, FlexDirection, Node, Val};
 
 /// Create a material UI composable.
 ///
-/// This will provide a [`Theme`] and set the background for its content.
+/// This will provide a [`Theme`], [`ModalContext`], and [`ShortcutContext`](crate::ecs::ShortcutContext), and set the background for its content.
 pub fn material_ui<'a, C: Compose>(content: C) -> MaterialUi<'a, C> {
     MaterialUi {
         content,
         theme: Theme::default(),
+        density: Density::default(),
+        scale_factor: 1.,
         modifier: Modifier::default(),
     }
 }
 
+/// Density of a [`MaterialUi`].
+///
+/// This scales the [`Spacing`](super::Spacing) tokens used by material components.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Density {
+    /// Comfortable density (the default spacing).
+    #[default]
+    Comfortable,
+
+    /// Compact density (75% of the default spacing).
+    Compact,
+}
+
+impl Density {
+    /// Get the spacing scale factor of this density.
+    pub fn scale(self) -> f32 {
+        match self {
+            Density::Comfortable => 1.,
+            Density::Compact => 0.75,
+        }
+    }
+}
+
 /// Material UI composable.
 ///
 /// For more see [`material_ui`].
@@ -26,6 +51,8 @@ pub fn material_ui<'a, C: Compose>(content: C) -> MaterialUi<'a, C> {
 pub struct MaterialUi<'a, C> {
     content: C,
     theme: Theme,
+    density: Density,
+    scale_factor: f32,
     modifier: Modifier<'a>,
 }
 
@@ -35,11 +62,30 @@ impl<'a, C> MaterialUi<'a, C> {
         self.theme = theme;
         self
     }
+
+    /// Set the density of this composable, scaling spacing throughout its content.
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set the scale factor of this composable, scaling typography and spacing throughout its content.
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
 }
 
 impl<'a, C: Compose> Compose for MaterialUi<'a, C> {
     fn compose(cx: Scope<Self>) -> impl Compose {
-        let theme = use_provider(&cx, || cx.me().theme.clone());
+        // Only re-scale (and re-provide) the theme when its inputs have changed.
+        let (theme, density, scale_factor) =
+            (cx.me().theme.clone(), cx.me().density, cx.me().scale_factor);
+        let theme = use_provider_memo(&cx, (theme.clone(), density, scale_factor), || {
+            theme.scaled(scale_factor, density)
+        });
+
+        use_provider(&cx, ModalContext::default);
 
         cx.me()
             .modifier
@@ -52,7 +98,9 @@ impl<'a, C: Compose> Compose for MaterialUi<'a, C> {
                 },
                 BackgroundColor(theme.colors.background),
             )))
-            .content(unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) })
+            .content(shortcuts(unsafe {
+                Signal::map_unchecked(cx.me(), |me| &me.content)
+            }))
     }
 }
 
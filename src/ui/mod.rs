@@ -1,24 +1,84 @@
 use crate::{
     ecs::{spawn, use_world, Modifier, Modify},
-    prelude::Compose,
-    use_mut, Scope, Signal, SignalMut,
+    prelude::{Compose, IntoCompose},
+    use_cell, use_mut, use_provider, Scope, Signal, SignalMut,
 };
 use actuate_macros::Data;
 use bevy_ecs::prelude::*;
+use bevy_hierarchy::Children;
 use bevy_input::{
     mouse::{MouseScrollUnit, MouseWheel},
     prelude::*,
 };
-use bevy_picking::prelude::*;
-use bevy_ui::prelude::*;
-use std::mem;
+use bevy_math::Vec2;
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{prelude::*, ComputedNode};
+use std::{borrow::Cow as StdCow, mem, rc::Rc};
+
+mod flex;
+pub use self::flex::{column, row, Flex};
+
+mod focus;
+pub use self::focus::{use_focus, use_focusable, FocusContext};
+
+mod hover;
+pub use self::hover::use_hover;
+
+mod lazy;
+pub use self::lazy::{lazy, Lazy, VisibilityContext};
+
+mod tooltip;
+pub use self::tooltip::{tooltip, Tooltip};
 
 #[cfg(feature = "material")]
 #[cfg_attr(docsrs, doc(cfg(feature = "material")))]
 /// Material UI.
 pub mod material;
 
+/// Compose this string as a default-styled [`Text`] node.
+impl Compose for String {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        spawn(Text::new(cx.me().as_str()))
+    }
+}
+
+/// Compose this string as a default-styled [`Text`] node.
+impl Compose for &str {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        spawn(Text::new(*cx.me()))
+    }
+}
+
+/// Compose this string as a default-styled [`Text`] node.
+impl Compose for StdCow<'_, str> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        spawn(Text::new(&**cx.me()))
+    }
+}
+
+macro_rules! impl_into_compose_for_display {
+    ($($t:ty),*) => {
+        $(
+            /// Convert this value to a [`String`], composed as a default-styled [`Text`] node.
+            impl IntoCompose for $t {
+                type Output = String;
+
+                fn into_compose(self) -> Self::Output {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_compose_for_display!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char
+);
+
 /// Create a scroll view.
+///
+/// The scroll view provides a [`VisibilityContext`] to its content,
+/// so [`lazy`] content is only composed when scrolled into view.
 pub fn scroll_view<'a, C: Compose>(content: C) -> ScrollView<'a, C> {
     ScrollView {
         content,
@@ -26,6 +86,8 @@ pub fn scroll_view<'a, C: Compose>(content: C) -> ScrollView<'a, C> {
         modifier: Modifier::default(),
         scroll_x: true,
         scroll_y: true,
+        on_scroll: Rc::new(|_| {}),
+        on_scroll_end: Rc::new(|| {}),
     }
 }
 
@@ -38,9 +100,11 @@ pub struct ScrollView<'a, C> {
     scroll_x: bool,
     scroll_y: bool,
     modifier: Modifier<'a>,
+    on_scroll: Rc<dyn Fn(Vec2) + 'a>,
+    on_scroll_end: Rc<dyn Fn() + 'a>,
 }
 
-impl<C> ScrollView<'_, C> {
+impl<'a, C> ScrollView<'a, C> {
     /// Set the line size to scroll (default: 30).
     pub fn line_size(mut self, size: f32) -> Self {
         self.line_size = size;
@@ -58,18 +122,38 @@ impl<C> ScrollView<'_, C> {
         self.scroll_y = scroll_y;
         self
     }
+
+    /// Set a function to call with the current scroll offset whenever it changes.
+    pub fn on_scroll(mut self, f: impl Fn(Vec2) + 'a) -> Self {
+        self.on_scroll = Rc::new(f);
+        self
+    }
+
+    /// Set a function to call when this view is scrolled to (within a line of) the bottom of its content.
+    ///
+    /// This is called once each time the end is reached, so it can be used to load more content.
+    pub fn on_scroll_end(mut self, f: impl Fn() + 'a) -> Self {
+        self.on_scroll_end = Rc::new(f);
+        self
+    }
 }
 
 impl<C: Compose> Compose for ScrollView<'_, C> {
     fn compose(cx: Scope<Self>) -> impl Compose {
-        let is_hovered = use_mut(&cx, || false);
+        let (is_hovered, hover) = use_hover(&cx);
 
         let entity_cell = use_mut(&cx, || None);
+        let visibility_cx = use_provider(&cx, VisibilityContext::default);
+
+        let last_offset = use_cell(&cx, || Vec2::ZERO);
+        let was_at_end = use_cell(&cx, || false);
 
         use_world(
             &cx,
             move |mut mouse_wheel_events: EventReader<MouseWheel>,
                   mut scrolled_node_query: Query<&mut ScrollPosition>,
+                  computed_node_query: Query<(&ComputedNode, &GlobalTransform)>,
+                  children_query: Query<&Children>,
                   keyboard_input: Res<ButtonInput<KeyCode>>| {
                 for mouse_wheel_event in mouse_wheel_events.read() {
                     let (mut dx, mut dy) = match mouse_wheel_event.unit {
@@ -102,6 +186,45 @@ impl<C: Compose> Compose for ScrollView<'_, C> {
                         }
                     }
                 }
+
+                let Some(entity) = *entity_cell else {
+                    return;
+                };
+                let Ok(scroll_position) = scrolled_node_query.get(entity) else {
+                    return;
+                };
+
+                let offset = Vec2::new(scroll_position.offset_x, scroll_position.offset_y);
+                if last_offset.replace(offset) != offset {
+                    (cx.me().on_scroll)(offset);
+                }
+
+                if let Ok((node, _)) = computed_node_query.get(entity) {
+                    // Measure the content from the edges of the children (including gaps between them),
+                    // offset by this node's top padding and border to match the layout's scroll bounds.
+                    let (top, bottom) = children_query
+                        .get(entity)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|child| computed_node_query.get(*child).ok())
+                        .map(|(child, transform)| {
+                            let y = transform.translation().y;
+                            (y - child.size().y / 2., y + child.size().y / 2.)
+                        })
+                        .reduce(|(top, bottom), (child_top, child_bottom)| {
+                            (top.min(child_top), bottom.max(child_bottom))
+                        })
+                        .unwrap_or_default();
+                    let content_height = node.content_inset().top + bottom - top;
+                    let max_offset =
+                        (content_height - node.size().y).max(0.) * node.inverse_scale_factor();
+
+                    let is_at_end = max_offset > 0. && offset.y >= max_offset - cx.me().line_size;
+                    if is_at_end && !was_at_end.get() {
+                        (cx.me().on_scroll_end)();
+                    }
+                    was_at_end.set(is_at_end);
+                }
             },
         );
 
@@ -109,16 +232,17 @@ impl<C: Compose> Compose for ScrollView<'_, C> {
         let modifier: &Modifier = unsafe { mem::transmute(modifier) };
 
         modifier
-            .apply(
+            .apply(hover(
                 spawn(Node {
                     flex_direction: FlexDirection::Column,
                     overflow: Overflow::scroll_y(),
                     ..Default::default()
                 })
-                .on_spawn(move |entity| SignalMut::set(entity_cell, Some(entity.id())))
-                .observe(move |_: Trigger<Pointer<Over>>| SignalMut::set(is_hovered, true))
-                .observe(move |_: Trigger<Pointer<Out>>| SignalMut::set(is_hovered, false)),
-            )
+                .on_spawn(move |entity| {
+                    visibility_cx.set_viewport(Some(entity.id()));
+                    SignalMut::set(entity_cell, Some(entity.id()))
+                }),
+            ))
             .content(unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) })
     }
 }
@@ -0,0 +1,126 @@
+use crate::{
+    compose::Compose,
+    data::Data,
+    ecs::{spawn, use_bundle, use_world, Modifier, Modify},
+    use_mut, Scope, Signal, SignalMut,
+};
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{prelude::*, ComputedNode};
+use std::mem;
+
+/// Create a tooltip that shows `content` below the `anchor` entity.
+///
+/// The tooltip is spawned in its own top-level overlay node (with a [`GlobalZIndex`] above other UI),
+/// so it isn't clipped by the anchor's scroll containers.
+/// The anchor's position is read on every frame, so the tooltip follows the anchor as it moves.
+/// The tooltip is hidden until the anchor has been laid out.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Help;
+///
+/// impl Compose for Help {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let anchor = use_mut(&cx, || None);
+///         let (is_hovered, hover) = use_hover(&cx);
+///
+///         (
+///             hover(spawn(Text::new("?")))
+///                 .on_spawn(move |entity| SignalMut::set(anchor, Some(entity.id()))),
+///             anchor
+///                 .filter(|_| *is_hovered)
+///                 .map(|anchor| tooltip(anchor, "Opens the help menu.")),
+///         )
+///     }
+/// }
+/// ```
+pub fn tooltip<'a, C: Compose>(anchor: Entity, content: C) -> Tooltip<'a, C> {
+    Tooltip {
+        anchor,
+        content,
+        offset: Vec2::new(0., 4.),
+        modifier: Modifier::default(),
+    }
+}
+
+/// Tooltip composable.
+///
+/// For more see [`tooltip`].
+#[derive(Data)]
+#[actuate(path = "crate")]
+pub struct Tooltip<'a, C> {
+    anchor: Entity,
+    content: C,
+    offset: Vec2,
+    modifier: Modifier<'a>,
+}
+
+impl<C> Tooltip<'_, C> {
+    /// Set the offset of this tooltip from the bottom-left corner of its anchor, in logical pixels (default: 4px down).
+    pub fn offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<C: Compose> Compose for Tooltip<'_, C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let position = use_mut(&cx, || None);
+
+        use_world(
+            &cx,
+            move |anchor_query: Query<(&ComputedNode, &GlobalTransform)>| {
+                let Ok((node, transform)) = anchor_query.get(cx.me().anchor) else {
+                    SignalMut::set_if_neq(position, None);
+                    return;
+                };
+
+                // Convert the anchor's bottom-left corner from physical to logical pixels.
+                let center = transform.translation().truncate();
+                let size = node.size();
+                let corner = Vec2::new(center.x - size.x / 2., center.y + size.y / 2.)
+                    * node.inverse_scale_factor();
+
+                SignalMut::set_if_neq(position, Some(corner + cx.me().offset));
+            },
+        );
+
+        // Spawn the overlay as a root node, outside of any clipping ancestors.
+        let overlay = use_bundle(&cx, || GlobalZIndex(i32::MAX));
+
+        let position = *position;
+
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(
+                spawn(Node {
+                    display: if position.is_some() {
+                        Display::Flex
+                    } else {
+                        Display::None
+                    },
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(position.unwrap_or_default().x),
+                    top: Val::Px(position.unwrap_or_default().y),
+                    ..Default::default()
+                })
+                .target(overlay),
+            )
+            .content(unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) })
+    }
+}
+
+impl<'a, C: Compose> Modify<'a> for Tooltip<'a, C> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
@@ -0,0 +1,72 @@
+use actuate::{composer::Composer, prelude::*};
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+#[derive(Default)]
+struct MockClock {
+    now: Cell<Duration>,
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
+
+#[derive(Data)]
+struct Animated {
+    clock: Rc<MockClock>,
+    target: Rc<Cell<f32>>,
+    out: Rc<Cell<f32>>,
+}
+
+impl Compose for Animated {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        // Re-compose on every frame to pick up changes to `target`.
+        let updater = use_mut(&cx, || ());
+        SignalMut::set(updater, ());
+
+        let value = use_animated_with_clock(
+            &cx,
+            &*cx.me().clock,
+            cx.me().target.get(),
+            Duration::from_secs(1),
+        );
+        cx.me().out.set(*value);
+    }
+}
+
+#[test]
+fn it_animates_with_clock() {
+    let clock = Rc::new(MockClock::default());
+    let target = Rc::new(Cell::new(0.));
+    let out = Rc::new(Cell::new(-1.));
+
+    let mut composer = Composer::new(Animated {
+        clock: clock.clone(),
+        target: target.clone(),
+        out: out.clone(),
+    });
+
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), 0.);
+
+    target.set(10.);
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), 0.);
+
+    clock.now.set(Duration::from_millis(500));
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), 5.);
+
+    clock.now.set(Duration::from_millis(750));
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), 7.5);
+
+    clock.now.set(Duration::from_secs(2));
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), 10.);
+}
@@ -1,5 +1,6 @@
 use actuate::{
-    composer::{Composer, TryComposeError},
+    compose::{AnyComposable, BoxedCompose},
+    composer::{ComposeProgress, Composer, Priority, TryComposeError},
     prelude::*,
 };
 use std::{
@@ -174,6 +175,75 @@ fn it_composes_dyn_compose() {
     assert_eq!(x.get(), 2);
 }
 
+#[test]
+fn it_composes_borrowed_dyn_compose() {
+    #[derive(Data)]
+    struct Show<'a> {
+        parent: Signal<'a, Parent>,
+    }
+
+    impl Compose for Show<'_> {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            // Number of times this scope has been composed.
+            let count = use_cell(&cx, || 0);
+            count.set(count.get() + 1);
+
+            let parent = cx.me().parent;
+            parent.out.borrow_mut().push((parent.value, count.get()));
+        }
+    }
+
+    #[derive(Data)]
+    struct Negate<'a> {
+        parent: Signal<'a, Parent>,
+    }
+
+    impl Compose for Negate<'_> {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let parent = cx.me().parent;
+            parent.out.borrow_mut().push((-parent.value, 0));
+        }
+    }
+
+    #[derive(Data)]
+    struct Parent {
+        value: i32,
+        out: Rc<RefCell<Vec<(i32, i32)>>>,
+    }
+
+    impl Compose for Parent {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            if cx.me().value == 2 {
+                dyn_compose(Negate { parent: cx.me() })
+            } else {
+                dyn_compose(Show { parent: cx.me() })
+            }
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let value = use_mut(cx, || 0);
+        if *value < 3 {
+            SignalMut::update(value, |x| *x += 1);
+        }
+
+        Parent {
+            value: *value,
+            out: handle.clone(),
+        }
+    }));
+
+    for _ in 0..4 {
+        composer.try_compose().unwrap();
+    }
+
+    // `Show` keeps its state while its type stays the same, and is replaced when it changes.
+    assert_eq!(*out.borrow(), [(0, 1), (1, 2), (-2, 0), (3, 1)]);
+}
+
 #[test]
 fn it_composes_from_iter() {
     #[derive(Data)]
@@ -233,3 +303,2031 @@ fn it_composes_memo() {
     assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
     assert_eq!(*x.borrow(), 1);
 }
+
+#[test]
+fn it_returns_uncaught_errors_from_children() {
+    #[derive(Data)]
+    struct Broken;
+
+    impl Compose for Broken {
+        fn compose(_cx: Scope<Self>) -> impl Compose {
+            "".parse::<i32>().map(|_| ()).map_err(Error::new)
+        }
+    }
+
+    #[derive(Data)]
+    struct Wrap;
+
+    impl Compose for Wrap {
+        fn compose(_cx: Scope<Self>) -> impl Compose {
+            Broken
+        }
+    }
+
+    let mut composer = Composer::new(Wrap);
+
+    let mut result = composer.try_compose();
+    while result.is_ok() {
+        result = composer.try_compose();
+    }
+
+    let Err(TryComposeError::Error(error)) = result else {
+        panic!("expected an error, got {result:?}");
+    };
+    assert_eq!(error.to_string(), "cannot parse integer from empty string");
+}
+
+#[test]
+fn it_catches_typed_errors() {
+    #[derive(Data)]
+    struct A;
+
+    impl Compose for A {
+        fn compose(_cx: Scope<Self>) -> impl Compose {
+            let _: i32 = "".parse().map_err(Error::new)?;
+
+            Ok(())
+        }
+    }
+
+    #[derive(Data)]
+    struct B;
+
+    impl Compose for B {
+        fn compose(_cx: Scope<Self>) -> impl Compose {
+            Err::<(), _>(Error::new(std::fmt::Error))
+        }
+    }
+
+    #[derive(Data)]
+    struct Wrap {
+        typed: Rc<Cell<i32>>,
+        untyped: Rc<Cell<i32>>,
+    }
+
+    impl Compose for Wrap {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let typed = cx.me().typed.clone();
+            let untyped = cx.me().untyped.clone();
+
+            catch(
+                move |_| untyped.set(untyped.get() + 1),
+                catch_typed(
+                    move |_: std::num::ParseIntError| typed.set(typed.get() + 1),
+                    (A, B),
+                ),
+            )
+        }
+    }
+
+    let typed = Rc::new(Cell::new(0));
+    let untyped = Rc::new(Cell::new(0));
+    let mut composer = Composer::new(Wrap {
+        typed: typed.clone(),
+        untyped: untyped.clone(),
+    });
+
+    composer.try_compose().unwrap();
+    assert_eq!(typed.get(), 1);
+    assert_eq!(untyped.get(), 1);
+}
+
+#[test]
+fn it_composes_any_composables() {
+    #[derive(Data)]
+    struct Wrap {
+        x: Rc<Cell<i32>>,
+    }
+
+    impl Compose for Wrap {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            vec![
+                AnyComposable::new(Counter {
+                    x: cx.me().x.clone(),
+                }),
+                AnyComposable::new(NonUpdateCounter {
+                    x: cx.me().x.clone(),
+                }),
+            ]
+        }
+    }
+
+    let x = Rc::new(Cell::new(0));
+    let mut composer = Composer::new(Wrap { x: x.clone() });
+
+    composer.try_compose().unwrap();
+    assert_eq!(x.get(), 2);
+
+    composer.try_compose().unwrap();
+    assert_eq!(x.get(), 3);
+}
+
+#[test]
+fn it_keeps_any_composable_state_by_type() {
+    #[derive(Data)]
+    struct A {
+        inits: Rc<Cell<i32>>,
+    }
+
+    impl Compose for A {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            use_ref(&cx, || cx.me().inits.set(cx.me().inits.get() + 1));
+        }
+    }
+
+    #[derive(Data)]
+    struct B {
+        inits: Rc<Cell<i32>>,
+    }
+
+    impl Compose for B {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            use_ref(&cx, || cx.me().inits.set(cx.me().inits.get() + 1));
+        }
+    }
+
+    #[derive(Data)]
+    struct Tabs {
+        inits: Rc<Cell<i32>>,
+    }
+
+    impl Compose for Tabs {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let step = use_mut(&cx, || 0);
+            if *step < 2 {
+                SignalMut::update(step, |x| *x += 1);
+            }
+
+            let inits = cx.me().inits.clone();
+            let last = if *step < 2 {
+                AnyComposable::new(A {
+                    inits: inits.clone(),
+                })
+            } else {
+                AnyComposable::new(B {
+                    inits: inits.clone(),
+                })
+            };
+
+            vec![AnyComposable::new(A { inits }), last]
+        }
+    }
+
+    let inits = Rc::new(Cell::new(0));
+    let mut composer = Composer::new(Tabs {
+        inits: inits.clone(),
+    });
+
+    composer.try_compose().unwrap();
+    assert_eq!(inits.get(), 2);
+
+    // Items keep their state while their types stay the same.
+    composer.try_compose().unwrap();
+    assert_eq!(inits.get(), 2);
+
+    // The last item's state is reset when its type changes.
+    composer.try_compose().unwrap();
+    assert_eq!(inits.get(), 3);
+}
+
+#[test]
+fn it_coalesces_pending_updates() {
+    #[derive(Data)]
+    struct A {
+        x: Rc<Cell<i32>>,
+    }
+
+    impl Compose for A {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let a = use_mut(&cx, || ());
+            let b = use_mut(&cx, || ());
+            SignalMut::set(a, ());
+            SignalMut::set(b, ());
+
+            cx.me().x.set(cx.me().x.get() + 1);
+        }
+    }
+
+    let x = Rc::new(Cell::new(0));
+    let mut composer = Composer::new(A { x: x.clone() });
+
+    composer.try_compose().unwrap();
+    assert_eq!(x.get(), 1);
+
+    composer.try_compose().unwrap();
+    assert_eq!(x.get(), 2);
+}
+
+#[test]
+fn it_composes_replaced_pending_children() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let out_handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let step = use_mut(cx, || 0);
+        if *step < 2 {
+            SignalMut::update(step, |x| *x += 1);
+        }
+
+        let out = out_handle.clone();
+        if *step < 2 {
+            // `A` is always pending, so it's still queued when it's replaced by `B`.
+            BoxedCompose::new(compose::from_fn(move |cx| {
+                let updater = use_mut(cx, || ());
+                SignalMut::set(updater, ());
+
+                out.borrow_mut().push("A");
+            }))
+        } else {
+            // `B` is queued at the same position as `A`.
+            BoxedCompose::new(compose::from_fn(move |_| out.borrow_mut().push("B")))
+        }
+    }));
+
+    for _ in 0..4 {
+        let _ = composer.try_compose();
+    }
+    assert_eq!(out.borrow().last(), Some(&"B"));
+}
+
+#[test]
+fn it_composes_budgeted() {
+    let x = Rc::new(Cell::new(0));
+    let a = x.clone();
+    let b = x.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |_| {
+        let a = a.clone();
+        let b = b.clone();
+
+        (
+            compose::from_fn(move |_| a.set(a.get() + 1)),
+            compose::from_fn(move |_| b.set(b.get() + 1)),
+        )
+    }));
+
+    assert_eq!(
+        composer.compose_budgeted(2).unwrap(),
+        ComposeProgress::Incomplete
+    );
+    assert_eq!(x.get(), 0);
+
+    assert_eq!(
+        composer.compose_budgeted(2).unwrap(),
+        ComposeProgress::Complete
+    );
+    assert_eq!(x.get(), 2);
+
+    assert_eq!(composer.compose_budgeted(2), Err(TryComposeError::Pending));
+}
+
+#[test]
+fn it_drops_children_before_parents() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let parent_log = log.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let log = parent_log.clone();
+        use_drop(cx, {
+            let log = log.clone();
+            move || log.borrow_mut().push("parent")
+        });
+        use_drop_ordered(cx, 1, {
+            let log = log.clone();
+            move || log.borrow_mut().push("parent (priority)")
+        });
+
+        compose::from_fn(move |cx| {
+            let log = log.clone();
+            use_drop(cx, move || log.borrow_mut().push("child"));
+        })
+    }));
+
+    composer.try_compose().unwrap();
+    assert!(log.borrow().is_empty());
+
+    drop(composer);
+    assert_eq!(*log.borrow(), ["child", "parent (priority)", "parent"]);
+}
+
+#[test]
+fn it_reports_needs_recompose() {
+    let x = Rc::new(Cell::new(0));
+    let mut composer = Composer::new(NonUpdateCounter { x: x.clone() });
+    assert!(composer.needs_recompose());
+
+    composer.try_compose().unwrap();
+    assert!(!composer.needs_recompose());
+
+    let mut composer = Composer::new(Counter { x: x.clone() });
+    composer.try_compose().unwrap();
+    assert!(composer.needs_recompose());
+}
+
+#[test]
+fn it_forwards_boxed_compose_names() {
+    #[derive(Data)]
+    struct A;
+
+    impl Compose for A {
+        fn compose(_cx: Scope<Self>) -> impl Compose {}
+    }
+
+    #[derive(Data)]
+    struct Wrap;
+
+    impl Compose for Wrap {
+        fn compose(_cx: Scope<Self>) -> impl Compose {
+            BoxedCompose::new(A)
+        }
+    }
+
+    let mut composer = Composer::new(Wrap);
+    composer.try_compose().unwrap();
+
+    assert_eq!(format!("{:?}", composer), "Composer(Wrap(A))");
+}
+
+#[test]
+fn it_maps_signal_mut_after_parent_updates() {
+    let out = Rc::new(Cell::new((0, 0)));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let items = use_mut(cx, || vec![0]);
+        handle.set((items.len(), items[0]));
+
+        if items.len() == 1 {
+            let first = SignalMut::map_mut(items, |items| &mut items[0]);
+
+            // Replace the parent's allocation before the mapped update is applied.
+            SignalMut::set(items, vec![1; 1000]);
+            MapMut::set(first, 2);
+        }
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), (1000, 2));
+}
+
+#[test]
+fn it_splits_signal_mut() {
+    let out = Rc::new(Cell::new((0, 0)));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let pair = use_mut(cx, || (0, 0));
+        handle.set(*pair);
+
+        let (a, b) = SignalMut::split2(pair, |(a, b)| (a, b));
+        if *a == 0 {
+            MapMut::set(a, 1);
+            MapMut::set(SignalMut::map_mut(pair, |(_, b)| b), 2);
+        } else {
+            MapMut::set_if_neq(b, 3);
+        }
+    }));
+
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), (0, 0));
+
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), (1, 2));
+
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), (1, 3));
+}
+
+#[test]
+fn it_reports_stats() {
+    let mut composer = Composer::new(compose::from_fn(|cx| {
+        use_ref(cx, || ());
+        use_mut(cx, || 0);
+    }));
+    assert_eq!(composer.stats().node_count, 1);
+
+    composer.try_compose().unwrap();
+
+    let stats = composer.stats();
+    assert_eq!(stats.node_count, 1);
+    assert!(stats.total_hooks >= 2);
+    assert_eq!(stats.task_count, 0);
+}
+
+#[test]
+fn it_reports_skipped_memos() {
+    let mut composer = Composer::new(compose::from_fn(|cx| {
+        let updater = use_mut(cx, || ());
+        SignalMut::set(updater, ());
+
+        memo((), compose::from_fn(|_| {}))
+    }));
+
+    composer.try_compose().unwrap();
+    let stats = composer.stats();
+    assert_eq!((stats.composed_count, stats.skipped_count), (3, 0));
+
+    composer.try_compose().unwrap();
+    let stats = composer.stats();
+    assert_eq!((stats.composed_count, stats.skipped_count), (2, 1));
+}
+
+#[test]
+fn it_binds_models() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let source = use_mut(cx, || 0);
+        let (value, set_value) = use_model(cx, source);
+        handle.borrow_mut().push(*value);
+
+        set_value(1);
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
+    assert_eq!(*out.borrow(), [0, 1]);
+}
+
+#[test]
+fn it_requests_recompose() {
+    let count = Rc::new(Cell::new(0));
+    let handle = count.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let recompose = use_recompose(cx);
+
+        handle.set(handle.get() + 1);
+        if handle.get() < 3 {
+            recompose();
+        }
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
+    assert_eq!(count.get(), 3);
+}
+
+#[test]
+fn it_keeps_hook_addresses() {
+    let addresses = Rc::new(RefCell::new(Vec::new()));
+    let handle = addresses.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let updater = use_mut(cx, || ());
+        SignalMut::set(updater, ());
+
+        // Mix small, large, and zero-sized hooks across multiple chunks.
+        let mut compose_addresses = Vec::new();
+        for i in 0..100 {
+            compose_addresses.push(use_ref(cx, || i as u8) as *const u8 as usize);
+            compose_addresses.push(use_ref(cx, || [i; 64]).as_ptr() as usize);
+            compose_addresses.push(use_ref(cx, || [i; 2048]).as_ptr() as usize);
+            compose_addresses.push(use_ref(cx, || ()) as *const () as usize);
+        }
+        handle.borrow_mut().push(compose_addresses);
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    let addresses = addresses.borrow();
+    assert_eq!(addresses[0], addresses[1]);
+}
+
+#[test]
+fn it_stores_hooks_larger_than_a_chunk() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        // Larger than the first chunk, but small enough to be stored in one.
+        let small = use_ref(cx, || 1u8);
+        let large = use_ref(cx, || [7u8; 600]);
+        handle.borrow_mut().push((*small, large[599]));
+    }));
+
+    composer.try_compose().unwrap();
+    assert_eq!(*out.borrow(), [(1, 7)]);
+}
+
+#[test]
+fn it_composes_high_priority_updates_first() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+
+    let make_child = |name: &'static str, priority: Priority| {
+        let out = out.clone();
+        compose::from_fn(move |cx| {
+            let count = use_mut(cx, || 0);
+            out.borrow_mut().push(name);
+
+            if *count == 0 {
+                SignalMut::update_with_priority(count, priority, |x| *x += 1);
+            }
+        })
+    };
+
+    let mut composer = Composer::new((
+        make_child("low", Priority::Low),
+        make_child("high", Priority::High),
+    ));
+
+    composer.try_compose().unwrap();
+    assert_eq!(*out.borrow(), ["low", "high"]);
+
+    out.borrow_mut().clear();
+    composer.try_compose().unwrap();
+    assert_eq!(*out.borrow(), ["high", "low"]);
+}
+
+#[test]
+fn it_composes_low_priority_ancestors_before_high_priority_children() {
+    #[derive(Data)]
+    struct Child<'a> {
+        items: Signal<'a, Vec<i32>>,
+        out: Rc<RefCell<Vec<i32>>>,
+    }
+
+    impl Compose for Child<'_> {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let count = use_mut(&cx, || 0);
+            if *count == 0 {
+                SignalMut::update(count, |x| *x += 1);
+            }
+
+            let third = Signal::map(cx.me().items, |items| &items[2]);
+            cx.me().out.borrow_mut().push(*third);
+        }
+    }
+
+    #[derive(Data)]
+    struct Parent {
+        out: Rc<RefCell<Vec<i32>>>,
+    }
+
+    impl Compose for Parent {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let items = use_mut(&cx, || vec![1, 2, 3]);
+
+            if items.len() == 3 {
+                // Shrink the list with a low priority, unmounting the child.
+                SignalMut::update_with_priority(items, Priority::Low, |items| items.truncate(1));
+
+                Some(Child {
+                    items: SignalMut::as_ref(items),
+                    out: cx.me().out.clone(),
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(Parent { out: out.clone() });
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    assert_eq!(*out.borrow(), [3]);
+}
+
+#[test]
+fn it_notifies_on_unmount() {
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        use_unmount_notify(cx, &tx, 1);
+    }));
+    composer.try_compose().unwrap();
+    assert!(rx.try_recv().is_err());
+
+    drop(composer);
+    assert_eq!(rx.try_recv(), Ok(1));
+}
+
+#[test]
+fn it_resets_branch_hooks() {
+    let branch = Rc::new(Cell::new(false));
+    let dropped = Rc::new(Cell::new(0));
+    let out = Rc::new(Cell::new(0));
+
+    let mut composer = Composer::new(compose::from_fn({
+        let branch = branch.clone();
+        let dropped = dropped.clone();
+        let out = out.clone();
+
+        move |cx| {
+            let updater = use_mut(cx, || ());
+            SignalMut::set(updater, ());
+
+            let key = branch.get();
+            let dropped = dropped.clone();
+            let value = use_branch(cx, key, move |cx| {
+                use_drop(cx, move || dropped.set(dropped.get() + 1));
+
+                let count = use_ref(cx, || Cell::new(0));
+                count.set(count.get() + 1);
+                count.get()
+            });
+            out.set(value);
+        }
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), 2);
+    assert_eq!(dropped.get(), 0);
+
+    branch.set(true);
+    composer.try_compose().unwrap();
+    assert_eq!(out.get(), 1);
+    assert_eq!(dropped.get(), 1);
+}
+
+#[test]
+fn it_reads_parent_context_after_providing() {
+    struct Depth(usize);
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let inner_out = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        use_provider(cx, || Depth(1));
+
+        let out = inner_out.clone();
+        compose::from_fn(move |cx| {
+            let before = use_context::<Depth>(cx).unwrap().0;
+            use_provider(cx, || Depth(before + 1));
+            let after = use_context::<Depth>(cx).unwrap().0;
+            out.borrow_mut().push((before, after));
+
+            let out = out.clone();
+            compose::from_fn(move |cx| {
+                out.borrow_mut()
+                    .push((use_context::<Depth>(cx).unwrap().0, 0));
+            })
+        })
+    }));
+    composer.try_compose().unwrap();
+
+    assert_eq!(*out.borrow(), [(1, 1), (2, 0)]);
+}
+
+#[test]
+fn it_provides_multiple_contexts() {
+    struct Depth(usize);
+
+    struct Name(&'static str);
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let inner_out = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        use_providers(cx, (|| Depth(1), || Name("a"), || Depth(2)));
+
+        let out = inner_out.clone();
+        compose::from_fn(move |cx| {
+            out.borrow_mut().push((
+                use_context::<Depth>(cx).unwrap().0,
+                use_context::<Name>(cx).unwrap().0,
+            ));
+        })
+    }));
+    composer.try_compose().unwrap();
+
+    assert_eq!(*out.borrow(), [(2, "a")]);
+}
+
+#[test]
+fn it_provides_root_context() {
+    struct Config(usize);
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let inner_out = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        inner_out
+            .borrow_mut()
+            .push(use_context::<Config>(cx).unwrap().0);
+
+        let out = inner_out.clone();
+        compose::from_fn(move |cx| {
+            out.borrow_mut().push(use_context::<Config>(cx).unwrap().0);
+        })
+    }))
+    .with_context(Config(1));
+    composer.try_compose().unwrap();
+
+    assert_eq!(*out.borrow(), [1, 1]);
+}
+
+#[test]
+fn it_memoizes_local_values() {
+    let dependency = Rc::new(Cell::new(0));
+    let calls = Rc::new(Cell::new(0));
+
+    let mut composer = Composer::new(compose::from_fn({
+        let dependency = dependency.clone();
+        let calls = calls.clone();
+
+        move |cx| {
+            let updater = use_mut(cx, || ());
+            SignalMut::set(updater, ());
+
+            let x = dependency.get();
+            let value = use_memo_local(cx, x, || {
+                calls.set(calls.get() + 1);
+                Rc::new(x)
+            });
+            assert_eq!(**value, dependency.get());
+        }
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(calls.get(), 1);
+
+    dependency.set(1);
+    composer.try_compose().unwrap();
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn it_drops_keyed_refs() {
+    struct Cache {
+        key: i32,
+        drops: Rc<Cell<i32>>,
+    }
+
+    impl Drop for Cache {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    let key = Rc::new(Cell::new(0));
+    let drops = Rc::new(Cell::new(0));
+
+    let mut composer = Composer::new(compose::from_fn({
+        let key = key.clone();
+        let drops = drops.clone();
+
+        move |cx| {
+            let updater = use_mut(cx, || ());
+            SignalMut::set(updater, ());
+
+            let cache = use_ref_keyed(cx, key.get(), || Cache {
+                key: key.get(),
+                drops: drops.clone(),
+            });
+
+            // The reference points to the value for the current key.
+            assert_eq!(cache.key, key.get());
+        }
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(drops.get(), 0);
+
+    key.set(1);
+    composer.try_compose().unwrap();
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn it_reports_initial_composition() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let updater = use_mut(cx, || ());
+        SignalMut::set(updater, ());
+
+        handle.borrow_mut().push(use_is_initial(cx));
+    }));
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    assert_eq!(*out.borrow(), [true, false]);
+}
+
+#[test]
+fn it_keeps_ref_cells_and_rcs() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let updater = use_mut(cx, || ());
+        SignalMut::set(updater, ());
+
+        let count = use_ref_cell(cx, || 0);
+        *count.borrow_mut() += 1;
+
+        let rc = use_rc(cx, || ());
+
+        let last = use_cell(cx, || 0);
+        let last = last.replace(*count.borrow());
+
+        handle
+            .borrow_mut()
+            .push((*count.borrow(), Rc::strong_count(&rc), last));
+    }));
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    assert_eq!(*out.borrow(), [(1, 2, 0), (2, 2, 1)]);
+}
+
+#[test]
+fn it_reports_changed_values() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let count = use_mut(cx, || 0);
+        if *count < 3 {
+            SignalMut::update(count, |x| *x += 1);
+        }
+
+        handle.borrow_mut().push(use_changed(cx, &(*count / 2)));
+    }));
+
+    for _ in 0..4 {
+        composer.try_compose().unwrap();
+    }
+    assert_eq!(*out.borrow(), [false, false, true, false]);
+}
+
+#[test]
+fn it_composes_siblings_in_order() {
+    #[derive(Data)]
+    struct Child {
+        idx: usize,
+        log: Rc<RefCell<Vec<usize>>>,
+    }
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let updater = use_mut(&cx, || ());
+            SignalMut::set(updater, ());
+
+            cx.me().log.borrow_mut().push(cx.me().idx);
+        }
+    }
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let handle = log.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |_| {
+        (
+            Child {
+                idx: 0,
+                log: handle.clone(),
+            },
+            Child {
+                idx: 1,
+                log: handle.clone(),
+            },
+            Child {
+                idx: 2,
+                log: handle.clone(),
+            },
+        )
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    assert_eq!(*log.borrow(), [0, 1, 2, 0, 1, 2]);
+}
+
+#[test]
+fn it_composes_indexed_iterators() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |_| {
+        let handle = handle.clone();
+        compose::from_iter_indexed(vec!['a', 'b', 'c'], move |idx, item| {
+            let handle = handle.clone();
+            compose::from_fn(move |_| handle.borrow_mut().push((idx, *item)))
+        })
+    }));
+    composer.try_compose().unwrap();
+
+    assert_eq!(*out.borrow(), [(0, 'a'), (1, 'b'), (2, 'c')]);
+}
+
+#[test]
+fn it_truncates_history_on_change_after_undo() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let value = use_mut(cx, || 0);
+        let history = use_history(cx, &*value, 3);
+        let step = use_ref(cx, || Cell::new(0));
+        handle
+            .borrow_mut()
+            .push(history.entries().iter().copied().collect::<Vec<_>>());
+
+        match step.replace(step.get() + 1) {
+            0..=3 => SignalMut::set(value, *value + 1),
+            4 => SignalMut::set(value, history.undo().unwrap()),
+            5 => SignalMut::set(value, 10),
+            _ => assert_eq!(history.redo(), None),
+        }
+    }));
+
+    for _ in 0..7 {
+        composer.try_compose().unwrap();
+    }
+
+    assert_eq!(
+        *out.borrow(),
+        [
+            vec![0],
+            vec![0, 1],
+            vec![0, 1, 2],
+            vec![1, 2, 3],
+            vec![2, 3, 4],
+            vec![2, 3, 4],
+            vec![2, 3, 10],
+        ]
+    );
+}
+
+#[test]
+fn it_retains_ok_content_on_error() {
+    let inits = Rc::new(Cell::new(0));
+    let errors = Rc::new(Cell::new(0));
+    let inits_handle = inits.clone();
+    let errors_handle = errors.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let step = use_mut(cx, || 0);
+        if *step < 2 {
+            SignalMut::update(step, |x| *x += 1);
+        }
+
+        let inits = inits_handle.clone();
+        let errors = errors_handle.clone();
+
+        let content = if *step == 1 {
+            "".parse::<i32>().map(|_| ()).map_err(Error::new)
+        } else {
+            Ok(())
+        }
+        .map(move |()| {
+            compose::from_fn(move |cx| {
+                use_ref(cx, || inits.set(inits.get() + 1));
+            })
+        });
+
+        catch(
+            move |_| errors.set(errors.get() + 1),
+            retained_result(content),
+        )
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
+
+    assert_eq!(inits.get(), 1);
+    assert_eq!(errors.get(), 1);
+}
+
+#[test]
+fn it_composes_updates_queued_while_retained() {
+    let values = Rc::new(RefCell::new(Vec::new()));
+    let values_handle = values.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let step = use_mut(cx, || 0);
+        if *step < 2 {
+            SignalMut::update(step, |x| *x += 1);
+        }
+
+        let values = values_handle.clone();
+        let content = if *step == 1 {
+            "".parse::<i32>().map(|_| ()).map_err(Error::new)
+        } else {
+            Ok(())
+        }
+        .map(move |()| {
+            // The memoized content is only composed by its own updates.
+            memo(
+                (),
+                compose::from_fn(move |cx| {
+                    let x = use_mut(cx, || 0);
+                    if use_is_initial(cx) {
+                        SignalMut::set(x, 1);
+                    }
+                    values.borrow_mut().push(*x);
+                }),
+            )
+        });
+
+        catch(|_| {}, retained_result(content))
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    // Inspecting the composer while the content is suspended only visits live content.
+    let _ = format!("{composer:?}");
+    composer.walk(|_| {});
+
+    composer.try_compose().unwrap();
+    assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
+
+    assert_eq!(*values.borrow(), [0, 1]);
+}
+
+#[test]
+fn it_memoizes_signals_by_generation() {
+    #[derive(Data)]
+    struct A {
+        x: Rc<Cell<i32>>,
+    }
+
+    impl Compose for A {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let updater = use_mut(&cx, || ());
+            SignalMut::set(updater, ());
+
+            let items = use_mut(&cx, Vec::<i32>::new);
+            if use_is_initial(&cx) {
+                SignalMut::update(items, |items| items.push(1));
+            }
+
+            memo(
+                SignalMut::as_ref(items),
+                NonUpdateCounter {
+                    x: cx.me().x.clone(),
+                },
+            )
+        }
+    }
+
+    let x = Rc::new(Cell::new(0));
+    let mut composer = Composer::new(A { x: x.clone() });
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(x.get(), 2);
+}
+
+#[test]
+fn it_memoizes_callbacks() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let step = use_mut(cx, || 0);
+        if *step < 3 {
+            SignalMut::update(step, |x| *x += 1);
+        }
+
+        let value = *step;
+        let f = use_callback_memo(cx, value / 2, move |()| value);
+        handle.borrow_mut().push(f(()));
+    }));
+
+    for _ in 0..4 {
+        composer.try_compose().unwrap();
+    }
+    assert_eq!(*out.borrow(), [0, 0, 2, 2]);
+}
+
+#[test]
+fn it_walks_nodes_in_tree_order() {
+    let x = Rc::new(Cell::new(0));
+
+    let mut composer = Composer::new(compose::from_fn(move |_| {
+        (
+            NonUpdateCounter { x: x.clone() },
+            NonUpdateCounter { x: x.clone() },
+        )
+    }));
+    composer.try_compose().unwrap();
+
+    let mut infos = Vec::new();
+    composer.walk(|info| infos.push(info));
+
+    let names: Vec<_> = infos
+        .iter()
+        .map(|info| (info.name.as_deref().map(str::to_owned), info.child_idx))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            (Some("FromFn".to_owned()), 0),
+            (None, 0),
+            (Some("NonUpdateCounter".to_owned()), 0),
+            (Some("NonUpdateCounter".to_owned()), 1),
+        ]
+    );
+
+    assert_eq!(infos[0].parent, None);
+    assert_eq!(infos[2].parent, Some(infos[1].key));
+    assert_eq!(infos[3].parent, Some(infos[1].key));
+}
+
+#[test]
+fn it_quantizes_memos() {
+    let count = Rc::new(Cell::new(0));
+    let handle = count.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let x = use_mut(cx, || 0.);
+        if *x < 2. {
+            SignalMut::update(x, |x| *x += 0.5);
+        }
+
+        let handle = handle.clone();
+        use_memo_quantized(cx, *x, 1., move || handle.set(handle.get() + 1));
+    }));
+
+    for _ in 0..5 {
+        composer.try_compose().unwrap();
+    }
+
+    // 0, 0.5, 1, 1.5, 2
+    assert_eq!(count.get(), 3);
+}
+
+#[test]
+fn it_invalidates_nodes() {
+    let x = Rc::new(Cell::new(0));
+    let handle = x.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |_| NonUpdateCounter {
+        x: handle.clone(),
+    }));
+    composer.try_compose().unwrap();
+    assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
+    assert_eq!(x.get(), 1);
+
+    let mut key = None;
+    composer.walk(|info| {
+        if info.name.as_deref() == Some("NonUpdateCounter") {
+            key = Some(info.key);
+        }
+    });
+    let key = key.unwrap();
+
+    composer.invalidate(key);
+    composer.try_compose().unwrap();
+    assert_eq!(x.get(), 2);
+}
+
+#[test]
+fn it_maps_ref_maps_by_generation() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let value = use_mut(cx, || ((1, 2), 3));
+        if use_is_initial(cx) {
+            SignalMut::update(value, |value| value.0 .1 = 4);
+        }
+
+        let inner = RefMap::map(RefMap::from(SignalMut::as_ref(value)), |x| &x.0);
+        let field = RefMap::map(inner, |x| &x.1);
+
+        let RefMap::Map(map) = field else {
+            panic!("Expected a mapped reference.");
+        };
+        handle
+            .borrow_mut()
+            .push((*map, map.generation() == value.generation()));
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(*out.borrow(), [(2, true), (4, true)]);
+}
+
+#[test]
+fn it_uses_streams() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let latest = use_stream(cx, || futures::stream::iter([1, 2]));
+        handle.borrow_mut().push(*latest);
+    }));
+
+    while composer.try_compose() != Err(TryComposeError::Pending) {}
+    // Both items are ready at once, so only the latest is composed.
+    assert_eq!(*out.borrow(), [None, Some(2)]);
+}
+
+#[test]
+fn it_tracks_pointer_backed_children() {
+    #[derive(Data)]
+    struct Child {
+        value: i32,
+        out: Rc<RefCell<Vec<i32>>>,
+    }
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            cx.me().out.borrow_mut().push(cx.me().value);
+        }
+    }
+
+    #[derive(Data)]
+    struct Parent {
+        value: i32,
+        out: Rc<RefCell<Vec<i32>>>,
+    }
+
+    impl Compose for Parent {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            (
+                Some(Child {
+                    value: cx.me().value,
+                    out: cx.me().out.clone(),
+                }),
+                Ok::<_, Error>(Child {
+                    value: cx.me().value + 100,
+                    out: cx.me().out.clone(),
+                }),
+            )
+        }
+    }
+
+    #[derive(Data)]
+    struct Wrap {
+        out: Rc<RefCell<Vec<i32>>>,
+    }
+
+    impl Compose for Wrap {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let value = use_mut(&cx, || 0);
+            if *value < 2 {
+                SignalMut::update(value, |x| *x += 1);
+            }
+
+            catch(
+                |_| {},
+                Parent {
+                    value: *value,
+                    out: cx.me().out.clone(),
+                },
+            )
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(Wrap { out: out.clone() });
+
+    for _ in 0..3 {
+        composer.try_compose().unwrap();
+    }
+    assert_eq!(*out.borrow(), [0, 100, 1, 101, 2, 102]);
+}
+
+#[test]
+fn it_re_provides_memoized_contexts() {
+    struct Config(i32);
+
+    #[derive(Data)]
+    struct Child {
+        out: Rc<RefCell<Vec<i32>>>,
+    }
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let config = use_context::<Config>(&cx).unwrap();
+            cx.me().out.borrow_mut().push(config.0);
+        }
+    }
+
+    #[derive(Data)]
+    struct Parent {
+        out: Rc<RefCell<Vec<i32>>>,
+    }
+
+    impl Compose for Parent {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let count = use_mut(&cx, || 0);
+            if *count < 2 {
+                SignalMut::update(count, |x| *x += 1);
+            }
+
+            let value = *count;
+            use_provider_memo(&cx, value, || Config(value));
+
+            // The child is only re-composed when the context changes.
+            memo(
+                (),
+                Child {
+                    out: cx.me().out.clone(),
+                },
+            )
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(Parent { out: out.clone() });
+
+    for _ in 0..4 {
+        let _ = composer.try_compose();
+    }
+    assert_eq!(*out.borrow(), [0, 1, 2]);
+}
+
+#[test]
+fn it_ignores_recompose_after_drop() {
+    let recompose = Rc::new(RefCell::new(None));
+    let recompose_handle = recompose.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let is_shown = use_mut(cx, || true);
+        if *is_shown {
+            SignalMut::set(is_shown, false);
+        }
+
+        let recompose = recompose_handle.clone();
+        is_shown.then(|| {
+            compose::from_fn(move |cx| {
+                *recompose.borrow_mut() = Some(use_recompose(cx));
+            })
+        })
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    recompose.borrow().as_ref().unwrap()();
+    assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
+}
+
+#[test]
+fn it_drops_old_context_values() {
+    struct Config(Rc<Cell<usize>>);
+
+    impl Drop for Config {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[derive(Data)]
+    struct Child;
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            use_context::<Config>(&cx).unwrap();
+        }
+    }
+
+    let drops = Rc::new(Cell::new(0));
+    let drops_handle = drops.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let count = use_mut(cx, || 0);
+        if *count < 10 {
+            SignalMut::update(count, |x| *x += 1);
+        }
+
+        let drops = drops_handle.clone();
+        use_provider_memo(cx, *count, || Config(drops));
+
+        Child
+    }));
+
+    for _ in 0..12 {
+        let _ = composer.try_compose();
+    }
+
+    // Only the current and previous values are kept by the provider and its child.
+    assert_eq!(drops.get(), 9);
+}
+
+#[test]
+fn it_shares_computed_contexts() {
+    #[derive(Clone, PartialEq)]
+    struct Half(i32);
+
+    #[derive(Data)]
+    struct Child {
+        out: Rc<RefCell<Vec<i32>>>,
+    }
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let half = use_context::<Half>(&cx).unwrap();
+            cx.me().out.borrow_mut().push(half.0);
+        }
+    }
+
+    #[derive(Data)]
+    struct Parent {
+        out: Rc<RefCell<Vec<i32>>>,
+        computes: Rc<Cell<i32>>,
+    }
+
+    impl Compose for Parent {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let count = use_mut(&cx, || 0);
+            if *count < 3 {
+                SignalMut::update(count, |x| *x += 1);
+            }
+
+            let value = *count;
+            let computes = cx.me().computes.clone();
+            use_computed(&cx, value, || {
+                computes.set(computes.get() + 1);
+                Half(value / 2)
+            });
+
+            // Each child is only re-composed when the computed value changes.
+            (
+                memo(
+                    (),
+                    Child {
+                        out: cx.me().out.clone(),
+                    },
+                ),
+                memo(
+                    (),
+                    Child {
+                        out: cx.me().out.clone(),
+                    },
+                ),
+            )
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let computes = Rc::new(Cell::new(0));
+    let mut composer = Composer::new(Parent {
+        out: out.clone(),
+        computes: computes.clone(),
+    });
+
+    for _ in 0..5 {
+        let _ = composer.try_compose();
+    }
+    assert_eq!(*out.borrow(), [0, 0, 1, 1]);
+    assert_eq!(computes.get(), 4);
+}
+
+#[test]
+fn it_composes_vecs() {
+    #[derive(Data)]
+    struct List {
+        x: Rc<Cell<i32>>,
+    }
+
+    impl Compose for List {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            (0..3)
+                .map(|_| NonUpdateCounter {
+                    x: cx.me().x.clone(),
+                })
+                .collect::<Vec<_>>()
+                .into_compose()
+        }
+    }
+
+    let x = Rc::new(Cell::new(0));
+    let mut composer = Composer::new(List { x: x.clone() });
+
+    composer.try_compose().unwrap();
+    assert_eq!(x.get(), 3);
+}
+
+#[test]
+fn it_keeps_slotted_state_across_reorders() {
+    #[derive(Data)]
+    struct Item {
+        id: char,
+        slot: usize,
+        out: Rc<RefCell<Vec<(char, usize, usize)>>>,
+    }
+
+    impl Compose for Item {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            // The slot this item was first composed in.
+            let first_slot = *use_ref(&cx, || cx.me().slot);
+
+            cx.me()
+                .out
+                .borrow_mut()
+                .push((cx.me().id, cx.me().slot, first_slot));
+        }
+    }
+
+    #[derive(Data)]
+    struct List {
+        out: Rc<RefCell<Vec<(char, usize, usize)>>>,
+    }
+
+    impl Compose for List {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let is_reversed = use_mut(&cx, || false);
+            if !*is_reversed {
+                SignalMut::set(is_reversed, true);
+            }
+
+            let ids = if *is_reversed { ['c', 'a'] } else { ['a', 'b'] };
+
+            compose::slotted(
+                ids.into_iter()
+                    .enumerate()
+                    .map(|(slot, id)| {
+                        (
+                            id,
+                            Item {
+                                id,
+                                slot,
+                                out: cx.me().out.clone(),
+                            },
+                        )
+                    })
+                    .collect(),
+            )
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(List { out: out.clone() });
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    assert_eq!(
+        *out.borrow(),
+        [('a', 0, 0), ('b', 1, 1), ('c', 0, 0), ('a', 1, 0)]
+    );
+}
+
+#[test]
+fn it_composes_pending_slotted_children_once_after_reorders() {
+    #[derive(Data)]
+    struct Item {
+        id: char,
+        out: Rc<RefCell<Vec<char>>>,
+    }
+
+    impl Compose for Item {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            // `b` is always pending, so it's still queued when it moves to another slot.
+            if cx.me().id == 'b' {
+                let updater = use_mut(&cx, || ());
+                SignalMut::set(updater, ());
+            }
+
+            cx.me().out.borrow_mut().push(cx.me().id);
+        }
+    }
+
+    #[derive(Data)]
+    struct List {
+        out: Rc<RefCell<Vec<char>>>,
+    }
+
+    impl Compose for List {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let is_reversed = use_mut(&cx, || false);
+            if !*is_reversed {
+                SignalMut::set(is_reversed, true);
+            }
+
+            let ids = if *is_reversed { ['b', 'a'] } else { ['a', 'b'] };
+
+            compose::slotted(
+                ids.into_iter()
+                    .map(|id| {
+                        (
+                            id,
+                            Item {
+                                id,
+                                out: cx.me().out.clone(),
+                            },
+                        )
+                    })
+                    .collect(),
+            )
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(List { out: out.clone() });
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    assert_eq!(*out.borrow(), ['a', 'b', 'b', 'a']);
+}
+
+#[test]
+fn it_keeps_map_entry_state_by_key() {
+    #[derive(Data)]
+    struct Entry {
+        key: u32,
+        value: char,
+        out: Rc<RefCell<Vec<(u32, char, char)>>>,
+    }
+
+    impl Compose for Entry {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            // The value this entry was first composed with.
+            let first_value = *use_ref(&cx, || cx.me().value);
+
+            cx.me()
+                .out
+                .borrow_mut()
+                .push((cx.me().key, cx.me().value, first_value));
+        }
+    }
+
+    #[derive(Data)]
+    struct Map {
+        out: Rc<RefCell<Vec<(u32, char, char)>>>,
+    }
+
+    impl Compose for Map {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let is_changed = use_mut(&cx, || false);
+            if !*is_changed {
+                SignalMut::set(is_changed, true);
+            }
+
+            let entries = if *is_changed {
+                [(3, 'd'), (1, 'a')]
+            } else {
+                [(3, 'c'), (2, 'b')]
+            };
+
+            let out = cx.me().out.clone();
+            compose::from_map(entries, move |key, value| Entry {
+                key: *key,
+                value: *value,
+                out: out.clone(),
+            })
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(Map { out: out.clone() });
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+
+    assert_eq!(
+        *out.borrow(),
+        [(2, 'b', 'b'), (3, 'c', 'c'), (1, 'a', 'a'), (3, 'd', 'c')]
+    );
+}
+
+#[test]
+fn it_indexes_signal_mut_vecs() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let handle = out.clone();
+
+    let mut composer = Composer::new(compose::from_fn(move |cx| {
+        let items = use_mut(cx, || vec![0, 1, 2]);
+        handle
+            .borrow_mut()
+            .push(((*items).clone(), items.generation()));
+
+        assert!(SignalMut::index_mut(items, 3).is_none());
+
+        let item = SignalMut::index_mut(items, 1).unwrap();
+        ElementMut::set_if_neq(item, 5);
+    }));
+
+    composer.try_compose().unwrap();
+    composer.try_compose().unwrap();
+    assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
+
+    assert_eq!(*out.borrow(), [(vec![0, 1, 2], 0), (vec![0, 5, 2], 1)]);
+}
+
+#[test]
+fn it_shuts_down_in_order() {
+    struct Guard(Rc<RefCell<Vec<&'static str>>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            self.0.borrow_mut().push("task");
+        }
+    }
+
+    #[derive(Data)]
+    struct Child {
+        log: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let log = cx.me().log.clone();
+            use_drop(&cx, move || log.borrow_mut().push("child"));
+        }
+    }
+
+    #[derive(Data)]
+    struct Parent {
+        log: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Compose for Parent {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let guard = Guard(cx.me().log.clone());
+            use_local_task(&cx, move || async move {
+                let _guard = guard;
+                futures::future::pending::<()>().await;
+            });
+
+            let log = cx.me().log.clone();
+            use_drop(&cx, move || log.borrow_mut().push("parent"));
+
+            Child {
+                log: cx.me().log.clone(),
+            }
+        }
+    }
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(Parent { log: log.clone() });
+    composer.try_compose().unwrap();
+    assert!(log.borrow().is_empty());
+
+    composer.shutdown();
+    assert_eq!(*log.borrow(), ["task", "child", "parent"]);
+}
+
+#[test]
+fn it_selects_from_contexts() {
+    struct Settings {
+        volume: i32,
+        is_muted: bool,
+    }
+
+    #[derive(Data)]
+    struct Child {
+        out: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let is_muted = use_selector(&cx, |settings: &Settings| settings.is_muted);
+            cx.me().out.borrow_mut().push(is_muted);
+        }
+    }
+
+    #[derive(Data)]
+    struct Parent {
+        out: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl Compose for Parent {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let volume = use_mut(&cx, || 0);
+            if *volume < 3 {
+                SignalMut::update(volume, |x| *x += 1);
+            }
+
+            let volume = *volume;
+            let settings = use_provider_memo(&cx, volume, || Settings {
+                volume,
+                is_muted: volume >= 2,
+            });
+            assert_eq!(settings.volume, volume);
+
+            memo(
+                (),
+                Child {
+                    out: cx.me().out.clone(),
+                },
+            )
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(Parent { out: out.clone() });
+
+    for _ in 0..5 {
+        let _ = composer.try_compose();
+    }
+    assert_eq!(*out.borrow(), [false, true]);
+}
+
+#[test]
+fn it_composes_suspense() {
+    #[derive(Data)]
+    struct Fallback {
+        log: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Compose for Fallback {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            cx.me().log.borrow_mut().push("fallback");
+
+            let log = cx.me().log.clone();
+            use_drop(&cx, move || log.borrow_mut().push("drop fallback"));
+        }
+    }
+
+    #[derive(Data)]
+    struct Content {
+        log: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Compose for Content {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            cx.me().log.borrow_mut().push("content");
+        }
+    }
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let (tx, rx) = futures::channel::oneshot::channel::<()>();
+
+    let rx = Cell::new(Some(rx));
+    let handle = log.clone();
+    let mut composer = Composer::new(compose::from_fn(move |_| {
+        let rx = rx.take();
+        let log = handle.clone();
+
+        suspense(Fallback { log: log.clone() }, async move {
+            if let Some(rx) = rx {
+                rx.await.unwrap();
+            }
+            Content { log }
+        })
+    }));
+
+    composer.try_compose().unwrap();
+    assert_eq!(*log.borrow(), ["fallback"]);
+
+    tx.send(()).unwrap();
+    for _ in 0..3 {
+        let _ = composer.try_compose();
+    }
+    assert_eq!(*log.borrow(), ["fallback", "drop fallback", "content"]);
+}
+
+#[test]
+#[should_panic(expected = "Hook #1 in `Conditional` changed type between compositions")]
+fn it_reports_conditional_hooks() {
+    #[derive(Data)]
+    struct Conditional;
+
+    impl Compose for Conditional {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let count = use_mut(&cx, || 0);
+            SignalMut::update(count, |x| *x += 1);
+
+            if *count == 0 {
+                use_ref(&cx, || 0u8);
+            } else {
+                use_ref(&cx, String::new);
+            }
+        }
+    }
+
+    let mut composer = Composer::new(Conditional);
+    for _ in 0..2 {
+        let _ = composer.try_compose();
+    }
+}
+
+#[test]
+#[should_panic(expected = "Hook #1 in `ConditionalMut` changed type between compositions")]
+fn it_reports_conditional_mut_hooks() {
+    #[derive(Data)]
+    struct ConditionalMut;
+
+    impl Compose for ConditionalMut {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let count = use_mut(&cx, || 0);
+            SignalMut::update(count, |x| *x += 1);
+
+            if *count == 0 {
+                use_mut(&cx, || 0u8);
+            } else {
+                use_mut(&cx, String::new);
+            }
+        }
+    }
+
+    let mut composer = Composer::new(ConditionalMut);
+    for _ in 0..2 {
+        let _ = composer.try_compose();
+    }
+}
+
+#[test]
+fn it_catches_panics_from_children() {
+    #[derive(Data)]
+    struct Child {
+        is_broken: bool,
+    }
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            if cx.me().is_broken {
+                panic!("boom");
+            }
+        }
+    }
+
+    #[derive(Data)]
+    struct Fallback {
+        is_composed: Rc<Cell<bool>>,
+    }
+
+    impl Compose for Fallback {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            cx.me().is_composed.set(true);
+        }
+    }
+
+    let payload = Rc::new(RefCell::new(None));
+    let is_composed = Rc::new(Cell::new(false));
+
+    let payload_handle = payload.clone();
+    let is_composed_handle = is_composed.clone();
+    let mut composer = Composer::new(compose::from_fn(move |_| {
+        let payload = payload_handle.clone();
+        catch_unwind(
+            move |error| *payload.borrow_mut() = error.downcast_ref::<&str>().copied(),
+            Fallback {
+                is_composed: is_composed_handle.clone(),
+            },
+            Child { is_broken: true },
+        )
+    }));
+
+    for _ in 0..3 {
+        let _ = composer.try_compose();
+    }
+
+    assert_eq!(*payload.borrow(), Some("boom"));
+    assert!(is_composed.get());
+}
+
+#[test]
+fn it_runs_post_compose_after_the_pass() {
+    #[derive(Data)]
+    struct Child {
+        out: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Compose for Child {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            cx.me().out.borrow_mut().push("child");
+        }
+    }
+
+    #[derive(Data)]
+    struct Parent {
+        out: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Compose for Parent {
+        fn compose(cx: Scope<Self>) -> impl Compose {
+            let is_measured = use_mut(&cx, || false);
+            cx.me().out.borrow_mut().push("parent");
+
+            let out = cx.me().out.clone();
+            use_post_compose(&cx, move || {
+                out.borrow_mut().push("post");
+
+                if !*is_measured {
+                    SignalMut::set(is_measured, true);
+                }
+            });
+
+            Child {
+                out: cx.me().out.clone(),
+            }
+        }
+    }
+
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut composer = Composer::new(Parent { out: out.clone() });
+
+    composer.try_compose().unwrap();
+    assert_eq!(*out.borrow(), ["parent", "child", "post"]);
+
+    out.borrow_mut().clear();
+    composer.try_compose().unwrap();
+    assert_eq!(*out.borrow(), ["parent", "child", "post"]);
+}
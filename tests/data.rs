@@ -0,0 +1,66 @@
+use actuate::prelude::*;
+
+#[derive(Data)]
+struct SizedArray<T, const N: usize>
+where
+    T: Clone + Send,
+{
+    value: T,
+    values: [u8; N],
+}
+
+fn assert_data<T: Data>(_: &T) {}
+
+#[test]
+fn it_derives_data_with_const_generics_and_where_clauses() {
+    let data: SizedArray<i32, 3> = SizedArray {
+        value: 0,
+        values: [1, 2, 3],
+    };
+    assert_data(&data);
+    assert_eq!(data.value, 0);
+    assert_eq!(data.values.len(), 3);
+}
+
+struct NotData(i32);
+
+#[derive(Data)]
+struct Skipped {
+    #[data(skip)]
+    not_data: NotData,
+}
+
+#[test]
+fn it_derives_data_with_skipped_fields() {
+    let data = Skipped {
+        not_data: NotData(1),
+    };
+    assert_data(&data);
+    assert_eq!(data.not_data.0, 1);
+}
+
+#[derive(Data, Props)]
+struct Label<'a> {
+    #[props(required)]
+    text: Cow<'a, String>,
+    size: f32,
+}
+
+#[test]
+fn it_builds_props_with_defaults() {
+    let label = Label::builder()
+        .text(Cow::Owned(String::from("Hello")))
+        .build();
+    assert_eq!(&*label.text, "Hello");
+    assert_eq!(label.size, 0.);
+
+    let label = Label::builder().text(Cow::default()).size(2.).build();
+    assert!(label.text.is_empty());
+    assert_eq!(label.size, 2.);
+}
+
+#[test]
+#[should_panic(expected = "Missing required prop `text` for `Label`.")]
+fn it_panics_on_missing_required_props() {
+    Label::builder().size(2.).build();
+}
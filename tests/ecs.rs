@@ -0,0 +1,71 @@
+#![cfg(feature = "ecs")]
+
+use actuate::prelude::*;
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::Children;
+
+#[derive(Clone, Component)]
+struct Index(usize);
+
+#[derive(Data)]
+struct Items;
+
+impl Compose for Items {
+    fn compose(_cx: Scope<Self>) -> impl Compose {
+        (spawn(Index(0)), spawn(Index(1)), spawn(Index(2)))
+    }
+}
+
+#[test]
+fn it_spawns_children_in_declaration_order() {
+    let mut app = App::new();
+    app.add_plugins(ActuatePlugin);
+
+    let entity = app.world_mut().spawn(Composition::new(Items)).id();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let children = world.get::<Children>(entity).unwrap().to_vec();
+    let indices: Vec<_> = children
+        .iter()
+        .map(|child| world.get::<Index>(*child).unwrap().0)
+        .collect();
+    assert_eq!(indices, [0, 1, 2]);
+}
+
+#[derive(Data)]
+struct Broken;
+
+impl Compose for Broken {
+    fn compose(_cx: Scope<Self>) -> impl Compose {
+        "".parse::<i32>().map(|_| ()).map_err(Error::new)
+    }
+}
+
+#[test]
+fn it_sends_composition_errors() {
+    let mut app = App::new();
+    app.add_plugins(ActuatePlugin);
+
+    let entity = app.world_mut().spawn(Composition::new(Broken)).id();
+
+    app.update();
+
+    let events = app.world().resource::<Events<CompositionError>>();
+    let errors: Vec<_> = events
+        .get_cursor()
+        .read(events)
+        .map(|event| (event.entity, event.error.clone()))
+        .collect();
+    assert_eq!(
+        errors,
+        [(
+            entity,
+            String::from("cannot parse integer from empty string")
+        )]
+    );
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "test-util")]
+
+use actuate::{
+    prelude::*,
+    test_util::{test_compose, SignalId},
+};
+
+#[derive(Data)]
+struct Child;
+
+impl Compose for Child {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let _ = cx;
+    }
+}
+
+#[derive(Data)]
+struct App;
+
+impl Compose for App {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let is_shown = use_mut(&cx, || false);
+
+        if *is_shown {
+            Some(Child)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn it_sets_signals_by_id() {
+    let mut harness = test_compose(App);
+    harness.compose();
+    harness.assert_tree("Composer(App(Option))");
+
+    harness.set(SignalId::root(0), true);
+    harness.compose();
+    harness.assert_tree("Composer(App(Option(Child)))");
+
+    harness.set(SignalId::root(0), false);
+    assert!(harness.compose_once());
+    harness.compose();
+    harness.assert_tree("Composer(App(Option))");
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "ui")]
+
+use actuate::{
+    composer::{Composer, TryComposeError},
+    prelude::*,
+};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Data)]
+struct Input {
+    id: usize,
+    focus_on: usize,
+    out: Rc<RefCell<Vec<(usize, bool)>>>,
+}
+
+impl Compose for Input {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let (is_focused, request_focus) = use_focus(&cx);
+
+        let step = use_mut(&cx, || 0);
+        if *step < 2 {
+            SignalMut::update(step, |x| *x += 1);
+        }
+
+        // Request focus for each input in turn.
+        if *step == cx.me().focus_on {
+            request_focus();
+        }
+
+        cx.me().out.borrow_mut().push((cx.me().id, *is_focused));
+    }
+}
+
+#[test]
+fn it_moves_focus_between_composables() {
+    let out = Rc::new(RefCell::new(Vec::new()));
+
+    let mut composer = Composer::new((
+        Input {
+            id: 0,
+            focus_on: 1,
+            out: out.clone(),
+        },
+        Input {
+            id: 1,
+            focus_on: 2,
+            out: out.clone(),
+        },
+    ))
+    .with_context(FocusContext::default());
+
+    // Compose until there are no more updates.
+    let mut result = composer.try_compose();
+    while result.is_ok() {
+        result = composer.try_compose();
+    }
+    assert_eq!(result, Err(TryComposeError::Pending));
+
+    let last = |id| {
+        out.borrow()
+            .iter()
+            .rev()
+            .find(|(x, _)| *x == id)
+            .map(|(_, is_focused)| *is_focused)
+    };
+    assert_eq!(last(0), Some(false));
+    assert_eq!(last(1), Some(true));
+    assert!(out.borrow().contains(&(0, true)));
+}